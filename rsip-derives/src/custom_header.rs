@@ -0,0 +1,43 @@
+use quote::quote;
+
+//unlike the rest of the derives, this one is meant to be used outside rsip, hence the
+//::rsip paths
+pub fn trait_methods(
+    struct_name: &syn::Ident,
+    display_name: Option<String>,
+    compact_name: Option<String>,
+) -> proc_macro2::TokenStream {
+    let name = match display_name {
+        Some(display_name) => display_name,
+        None => crate::kebab_case(struct_name.to_string()),
+    };
+    let compact_name = match compact_name {
+        Some(compact_name) => quote! { Some(#compact_name) },
+        None => quote! { None },
+    };
+
+    quote! {
+        impl ::rsip::headers::CustomHeader for #struct_name {
+            const NAME: &'static str = #name;
+            const COMPACT_NAME: Option<&'static str> = #compact_name;
+
+            fn parse(value: &str) -> Result<Self, ::rsip::Error> {
+                <Self as std::str::FromStr>::from_str(value).map_err(Into::into)
+            }
+
+            fn value(&self) -> String {
+                self.to_string()
+            }
+        }
+    }
+}
+
+pub fn into_header(struct_name: &syn::Ident) -> proc_macro2::TokenStream {
+    quote! {
+        impl std::convert::From<#struct_name> for ::rsip::Header {
+            fn from(from: #struct_name) -> Self {
+                ::rsip::Header::Custom(::rsip::headers::Custom::new(from))
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput};
 
+mod custom_header;
 mod newtype;
 mod to_typed_header;
 mod typed_header;
@@ -12,6 +13,7 @@ mod untyped_header;
 #[darling(default, attributes(header))]
 struct HeaderOpts {
     display_name: Option<String>,
+    compact_name: Option<String>,
     //TODO: this should be an enum with parse trait for better safety
     integer_type: Option<String>,
 }
@@ -43,6 +45,29 @@ pub fn untyped_header_signature(item: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+/// Implements `rsip::headers::CustomHeader` for an application defined header, using its
+/// `FromStr` and `Display` implementations, along with `From<T> for rsip::Header`. The header
+/// name defaults to the kebab-cased struct name, and can be set using
+/// `#[header(display_name = "X-Tenant", compact_name = "xt")]`.
+#[proc_macro_derive(CustomHeader, attributes(header))]
+pub fn custom_header_signature(item: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(item as DeriveInput);
+    let opts = HeaderOpts::from_derive_input(&ast).expect("Wrong options");
+
+    let struct_name = &ast.ident;
+
+    let trait_methods =
+        custom_header::trait_methods(struct_name, opts.display_name, opts.compact_name);
+    let into_header = custom_header::into_header(struct_name);
+
+    let expanded = quote! {
+        #trait_methods
+        #into_header
+    };
+
+    expanded.into()
+}
+
 #[proc_macro_derive(ToTypedHeader, attributes(header))]
 pub fn to_typed_header_signature(item: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(item as DeriveInput);
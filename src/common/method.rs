@@ -7,12 +7,15 @@ use std::convert::TryFrom;
 macro_rules! create_methods {
     ($($name:ident),*) => {
 
-        /// The SIP [Request](super::super::Request) method.
-        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        /// The SIP [Request](super::super::Request) method. This is not a `Copy` type because
+        /// any extension method (= not defined in any SIP RFC that rsip knows about) is kept
+        /// inside the `Other` variant, as found in the message.
+        #[derive(Debug, PartialEq, Eq, Clone)]
         pub enum Method {
             $(
                 $name,
             )*
+            Other(String),
         }
 
         impl Method {
@@ -31,6 +34,7 @@ macro_rules! create_methods {
                     $(
                         Self::$name => write!(f, "{}", stringify!($name).to_uppercase()),
                     )*
+                    Self::Other(method) => write!(f, "{}", method),
                 }
             }
         }
@@ -43,6 +47,7 @@ macro_rules! create_methods {
                     $(
                         part if part.trim().eq_ignore_ascii_case(stringify!($name)) => Ok(Method::$name),
                     )*
+                    part if is_extension_method(part.trim()) => Ok(Method::Other(part.trim().into())),
                     part => Err(Error::ParseError(format!("invalid method: {}", part))),
                 }
             }
@@ -55,6 +60,11 @@ create_methods!(
     Subscribe, Update
 );
 
+//an extension method is any token, as RFC3261 defines it
+fn is_extension_method(part: &str) -> bool {
+    !part.is_empty() && part.bytes().all(crate::parser_utils::is_token)
+}
+
 //TODO: not ideal performance here
 impl std::str::FromStr for Method {
     type Err = crate::Error;
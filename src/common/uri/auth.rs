@@ -2,22 +2,97 @@ pub use crate::Error;
 #[doc(hidden)]
 pub use tokenizer::Tokenizer;
 
+use crate::utils::{percent_decode_lossy, percent_encode};
+
 /// Simple struct that holds the authority part on of a URI.
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// Both `user` and `password` are kept percent-decoded (`alice%20smith` is parsed as
+/// `alice smith`), unless they can't be decoded (for instance when the escaped bytes are not
+/// valid UTF-8), in which case they are kept as found.
+///
+/// When parsed (or built using [Auth::from_escaped]), the escaped form found in the URI
+/// (`user[:password]`) is kept as well, available through [Auth::raw], so that the `Auth` is written back exactly the same way. Otherwise (or if `user` or
+/// `password` have changed since then), any character that is not allowed by
+/// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-25.1) is escaped when the
+/// `Auth` is displayed. The escaped form is also available through [Auth::escaped_user] and
+/// [Auth::escaped_password]. Only the decoded values are taken into account when comparing.
+#[derive(Debug, Eq, Clone, Default)]
 pub struct Auth {
     pub user: String,
     pub password: Option<String>,
+    raw: Option<String>,
+}
+
+impl Auth {
+    /// Creates an `Auth` out of the escaped user and password, as they appear in a URI, keeping
+    /// that escaped form so that it is written back the same way.
+    pub fn from_escaped(user: &str, password: Option<&str>) -> Self {
+        Self {
+            user: percent_decode_lossy(user),
+            password: password.map(percent_decode_lossy),
+            raw: Some(match password {
+                Some(password) => format!("{}:{}", user, password),
+                None => user.into(),
+            }),
+        }
+    }
+
+    /// Returns the escaped form (`user[:password]`) this `Auth` was parsed from, if any.
+    pub fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+
+    /// Returns the user, escaped as it should appear in a URI.
+    pub fn escaped_user(&self) -> String {
+        match self.raw_parts() {
+            Some((raw, _)) if percent_decode_lossy(raw) == self.user => raw.into(),
+            _ => percent_encode(&self.user, is_user_char),
+        }
+    }
+
+    /// Returns the password, escaped as it should appear in a URI.
+    pub fn escaped_password(&self) -> Option<String> {
+        self.password
+            .as_ref()
+            .map(|password| match self.raw_parts() {
+                Some((_, Some(raw))) if &percent_decode_lossy(raw) == password => raw.into(),
+                _ => percent_encode(password, is_password_char),
+            })
+    }
+
+    fn raw_parts(&self) -> Option<(&str, Option<&str>)> {
+        self.raw.as_deref().map(|raw| match raw.split_once(':') {
+            Some((user, password)) => (user, Some(password)),
+            None => (raw, None),
+        })
+    }
+}
+
+impl PartialEq for Auth {
+    fn eq(&self, other: &Self) -> bool {
+        self.user == other.user && self.password == other.password
+    }
 }
 
 impl std::fmt::Display for Auth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.password {
-            Some(password) => write!(f, "{}:{}", self.user, password),
-            None => write!(f, "{}", self.user),
+        match self.escaped_password() {
+            Some(password) => write!(f, "{}:{}", self.escaped_user(), password),
+            None => write!(f, "{}", self.escaped_user()),
         }
     }
 }
 
+//unreserved and user-unreserved characters of RFC3261
+pub(crate) fn is_user_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-_.!~*'()&=+$,;?/".contains(c)
+}
+
+//unreserved characters of RFC3261 along with the few extra that are allowed in a password
+pub(crate) fn is_password_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-_.!~*'()&=+$,".contains(c)
+}
+
 impl<T, S> From<(T, Option<S>)> for Auth
 where
     T: Into<String>,
@@ -27,6 +102,7 @@ where
         Self {
             user: from.0.into(),
             password: from.1.map(|p| p.into()),
+            ..Default::default()
         }
     }
 }
@@ -35,10 +111,7 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Auth
     type Error = Error;
 
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
-        Ok(Auth {
-            user: tokenizer.user.into(),
-            password: tokenizer.password.map(Into::into),
-        })
+        Ok(Auth::from_escaped(tokenizer.user, tokenizer.password))
     }
 }
 
@@ -93,39 +166,42 @@ pub mod tokenizer {
         I: AbstractInputItem<I>,
     {
         pub fn tokenize(part: T) -> GResult<T, Self> {
+            use crate::parser_utils::is_empty_or_fail_with;
             use nom::{
-                bytes::complete::{tag, take_till, take_until},
-                combinator::rest,
-                sequence::tuple,
+                bytes::complete::{tag, take_till, take_while, take_while1},
+                combinator::opt,
+                sequence::{preceded, tuple},
             };
 
-            let (rem, (auth, _)) = tuple((
-                // take_till(|c| c == Into::<I>::into(b'.') || c == Into::<I>::into(b'@')), //TODO why was dot here
-                take_till(|c|  c == Into::<I>::into(b'@')),
-                tag("@"),
-            ))(part)
+            //userinfo can't hold any whitespace or angle brackets, stopping there makes sure
+            //that we don't look for the `@` beyond the uri, for instance in a following header
+            let (rem, auth) = take_till(|c: I| {
+                c == Into::<I>::into(b'@') || " \t\r\n<>\"".contains(c.clone().as_char())
+            })(part)
             .map_err(|_: GenericNomError<'a, T>| {
                 TokenizerError::from(("auth user", part)).into()
             })?;
+            let (rem_after_auth, _) = tag("@")(rem).map_err(|_: GenericNomError<'a, T>| {
+                TokenizerError::from(("auth user", part)).into()
+            })?;
 
-            let (user, password) = match tuple::<_, _, nom::error::VerboseError<T>, _>((
-                take_until(":"),
-                tag(":"),
-                rest,
-            ))(auth)
-            {
-                Ok((_, (user, _, password))) => (user, Some(password)),
-                Err(_) => {
-                    //this is not going to ever fail actually, since rest never returns an
-                    //error
-                    let (_, user) = rest(auth).map_err(|_: GenericNomError<'a, T>| {
-                        TokenizerError::from(("auth user (no password)", auth)).into()
-                    })?;
-                    (user, None)
-                }
+            let is_user_char = |c: I| {
+                let c = c.as_char();
+                c == '%' || super::is_user_char(c)
             };
+            let is_password_char = |c: I| {
+                let c = c.as_char();
+                c == '%' || super::is_password_char(c)
+            };
+
+            let (auth_rem, (user, password)) = tuple((
+                take_while1(is_user_char),
+                opt(preceded(tag(":"), take_while(is_password_char))),
+            ))(auth)
+            .map_err(|_: GenericNomError<'a, T>| TokenizerError::from(("auth", rem)).into())?;
+            is_empty_or_fail_with(auth_rem, ("auth", rem))?;
 
-            Ok((rem, Tokenizer::from((user, password))))
+            Ok((rem_after_auth, Tokenizer::from((user, password))))
         }
     }
 }
@@ -138,6 +214,7 @@ impl testing_utils::Randomize for Auth {
         Self {
             user: rand_str_of(7),
             password: sample(&[Some(rand_str_of(10)), None]),
+            ..Default::default()
         }
     }
 }
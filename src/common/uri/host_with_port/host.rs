@@ -2,7 +2,7 @@ use crate::Error;
 use rsip_derives::NewType;
 use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 /// The `Host` enum represents the host part of the [HostWithPort](super::HostWithPort) struct
 ///
@@ -10,6 +10,10 @@ use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 ///
 /// * `Domain` that holds a [Domain] that represents a DNS domain.
 /// * `IpAddr` that holds an [IpAddr](std::net::IpAddr) and represents a raw IP address
+///
+/// IPv6 addresses are always written as IPv6 references, meaning inside brackets (like
+/// `[2001:db8::10]`), as [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-25.1)
+/// requires. When converting from a string, both the bracketed and the bare form are accepted.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Host {
     Domain(Domain),
@@ -25,10 +29,7 @@ impl Default for Host {
 impl std::str::FromStr for Host {
     type Err = crate::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.parse::<IpAddr>() {
-            Ok(ip_addr) => Ok(Host::IpAddr(ip_addr)),
-            Err(_) => Ok(Host::Domain(s.into())),
-        }
+        Ok(s.into())
     }
 }
 
@@ -53,6 +54,7 @@ impl std::fmt::Display for Host {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
             Host::Domain(domain) => write!(f, "{}", domain),
+            Host::IpAddr(IpAddr::V6(ip_addr)) => write!(f, "[{}]", ip_addr),
             Host::IpAddr(ip_addr) => write!(f, "{}", ip_addr),
         }
     }
@@ -66,7 +68,12 @@ impl From<String> for Host {
 
 impl From<&str> for Host {
     fn from(from: &str) -> Self {
-        match from.parse::<IpAddr>() {
+        let ip_addr = match from.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(ipv6) => ipv6.parse::<Ipv6Addr>().map(IpAddr::V6),
+            None => from.parse::<IpAddr>(),
+        };
+
+        match ip_addr {
             Ok(ip_addr) => Host::IpAddr(ip_addr),
             Err(_) => Host::Domain(from.into()),
         }
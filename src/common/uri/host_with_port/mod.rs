@@ -9,7 +9,7 @@ pub use port::Port;
 
 use crate::Error;
 use std::convert::{TryFrom, TryInto};
-use std::net::{IpAddr, SocketAddr};
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
 
 /// Simple struct that holds the [Host] and [Port] of a SIP(S) uri, reprsented by [Uri](super::Uri).
 /// Note that during parsing, if no port is set, it is returned as `None`. Usually when no port
@@ -23,6 +23,24 @@ pub struct HostWithPort {
     pub port: Option<Port>,
 }
 
+impl HostWithPort {
+    /// Compares two `HostWithPort` according to the URI comparison rules of
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.4): domains are
+    /// compared ignoring case and IP addresses by value, while a missing port never matches an
+    /// explicit port, even if that is the default one.
+    pub fn equivalent(&self, other: &HostWithPort) -> bool {
+        let hosts_match = match (&self.host, &other.host) {
+            (Host::Domain(domain), Host::Domain(other)) => {
+                domain.to_string().eq_ignore_ascii_case(&other.to_string())
+            }
+            (Host::IpAddr(ip_addr), Host::IpAddr(other)) => ip_addr == other,
+            _ => false,
+        };
+
+        hosts_match && self.port == other.port
+    }
+}
+
 impl std::fmt::Display for HostWithPort {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match (&self.host, self.port.as_ref()) {
@@ -44,6 +62,25 @@ impl TryFrom<&str> for HostWithPort {
     type Error = Error;
 
     fn try_from(from: &str) -> Result<Self, Self::Error> {
+        if let Some(ipv6) = from.strip_prefix('[') {
+            return match ipv6.split_once(']') {
+                Some((host, "")) => Ok(IpAddr::V6(host.parse()?).into()),
+                Some((host, port)) => match port.strip_prefix(':') {
+                    Some(port) => {
+                        Ok((IpAddr::V6(host.parse()?), TryInto::<Port>::try_into(port)?).into())
+                    }
+                    None => Err(Error::ParseError(format!(
+                        "invalid IPv6 reference: {}",
+                        from
+                    ))),
+                },
+                None => Err(Error::ParseError(format!(
+                    "invalid IPv6 reference: {}",
+                    from
+                ))),
+            };
+        }
+
         match from.rsplit_once(':') {
             None => Ok(Host::from(from).into()),
             Some((host, port)) => Ok((
@@ -133,9 +170,14 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Host
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
         use std::str::FromStr;
 
-        let host = match IpAddr::from_str(tokenizer.host) {
-            Ok(ip_addr) => Host::IpAddr(ip_addr),
-            Err(_) => Host::Domain(tokenizer.host.into()),
+        let host = match tokenizer.host.strip_prefix('[') {
+            Some(ipv6) => Host::IpAddr(IpAddr::V6(Ipv6Addr::from_str(
+                ipv6.strip_suffix(']').unwrap_or(ipv6),
+            )?)),
+            None => match IpAddr::from_str(tokenizer.host) {
+                Ok(ip_addr) => Host::IpAddr(ip_addr),
+                Err(_) => Host::Domain(tokenizer.host.into()),
+            },
         };
 
         let port = match tokenizer.port {
@@ -204,11 +246,18 @@ pub mod tokenizer {
                 sequence::tuple,
             };
 
-            let (rem, host_with_port) =
-                take_till1(|c| c == Into::<I>::into(b';') || c == Into::<I>::into(b' '))(part)
-                    .map_err(|_: GenericNomError<'a, T>| {
-                        TokenizerError::from(("host with port", part)).into()
-                    })?;
+            if let Ok((rem, (host, port))) = Self::tokenize_ipv6_reference(part) {
+                return Ok((rem, (host, port).into()));
+            }
+
+            let (rem, host_with_port) = take_till1(|c| {
+                c == Into::<I>::into(b';')
+                    || c == Into::<I>::into(b' ')
+                    || c == Into::<I>::into(b'?')
+            })(part)
+            .map_err(|_: GenericNomError<'a, T>| {
+                TokenizerError::from(("host with port", part)).into()
+            })?;
 
             let (host, port) = match tuple::<_, _, nom::error::VerboseError<T>, _>((
                 take_until(":"),
@@ -229,6 +278,24 @@ pub mod tokenizer {
 
             Ok((rem, (host, port).into()))
         }
+
+        //an IPv6 reference is enclosed in brackets, since the address itself contains colons,
+        //like `[2001:db8::10]:5060`. The host keeps the brackets.
+        fn tokenize_ipv6_reference(part: T) -> GResult<T, (T, Option<T>)> {
+            use nom::{
+                bytes::complete::{tag, take_until, take_while1},
+                combinator::{opt, recognize},
+                sequence::{preceded, tuple},
+            };
+
+            tuple((
+                recognize(tuple((tag("["), take_until("]"), tag("]")))),
+                opt(preceded(tag(":"), take_while1(|c: I| c.is_dec_digit()))),
+            ))(part)
+            .map_err(|_: GenericNomError<'a, T>| {
+                TokenizerError::from(("host with port (ipv6 reference)", part)).into()
+            })
+        }
     }
 }
 
@@ -2,6 +2,8 @@ pub mod auth;
 pub mod host_with_port;
 pub mod param;
 pub mod scheme;
+pub mod tel_uri;
+pub mod uri_header;
 pub mod uri_with_params;
 pub mod uri_with_params_list;
 
@@ -12,10 +14,12 @@ pub use auth::Auth;
 pub use host_with_port::{Domain, Host, HostWithPort, Port};
 pub use param::Param;
 pub use scheme::Scheme;
+pub use tel_uri::TelUri;
+pub use uri_header::{UriHeader, UriHeaders};
 pub use uri_with_params::UriWithParams;
 pub use uri_with_params_list::UriWithParamsList;
 
-use crate::{Error, Transport};
+use crate::{Error, Header, Headers, Transport};
 use std::convert::{TryFrom, TryInto};
 
 /// A very flexible SIP(S) URI.
@@ -24,16 +28,43 @@ use std::convert::{TryFrom, TryInto};
 /// is specified then port 5060 is assumed. But rsip is not acting smart here and delegates that
 /// responsibility to you because you might want 5061 (TLS) as default etc.
 /// Similarly on generation, if no port is specified, no port is set at all in the final string.
+///
+/// Any headers found in the URI (like `?Subject=hi&Priority=urgent`) are held in `headers` as
+/// [UriHeaders]. Those are not meant to be sent as part of a Request-URI: when building a
+/// request out of such a URI, use [Uri::header_fields] to get the actual header fields.
+///
+/// URIs of any other scheme (like `mailto:alice@atlanta.com`, `urn:service:sos` or
+/// `https://www.example.com/alice/photo.jpg`) are not SIP-shaped, hence they are kept as
+/// [RFC3986](https://datatracker.ietf.org/doc/html/rfc3986#section-4.3) absolute URIs: the
+/// scheme goes to `scheme` and everything after the `:` is kept verbatim in `opaque`, leaving
+/// the rest of the fields empty. Use [Uri::is_sip] to tell those apart.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct Uri {
     pub scheme: Option<Scheme>,
     pub auth: Option<Auth>,
     pub host_with_port: HostWithPort,
     pub params: Vec<Param>,
-    pub headers: Vec<u32>,
+    pub headers: UriHeaders,
+    pub opaque: Option<String>,
 }
 
 impl Uri {
+    /// Checks if this is a SIP(S) URI. URIs without a scheme, like the ones found in `Via`
+    /// headers, are considered SIP URIs as well.
+    pub fn is_sip(&self) -> bool {
+        self.opaque.is_none()
+            && self
+                .scheme
+                .as_ref()
+                .map(|scheme| scheme.is_sip())
+                .unwrap_or(true)
+    }
+
+    /// Checks if this is an absolute URI of a non SIP-shaped scheme, held in `opaque`.
+    pub fn is_opaque(&self) -> bool {
+        self.opaque.is_some()
+    }
+
     pub fn user(&self) -> Option<&str> {
         self.auth.as_ref().map(|auth| auth.user.as_ref())
     }
@@ -53,6 +84,73 @@ impl Uri {
         })
     }
 
+    /// Returns the header fields that a request built out of this URI should include, as
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.5) describes. The
+    /// special `body` header is not included, check [Uri::body].
+    pub fn header_fields(&self) -> Result<Headers, Error> {
+        Ok(self
+            .headers
+            .iter()
+            .filter(|header| !header.is_body())
+            .cloned()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Header>, Error>>()?
+            .into())
+    }
+
+    /// Returns the value of the special `body` URI header, if any, which holds the body of a
+    /// request built out of this URI.
+    pub fn body(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.is_body())
+            .map(|header| header.value())
+    }
+
+    /// Returns a copy of the URI without any headers, as it should appear in a Request-URI.
+    pub fn without_headers(&self) -> Self {
+        Self {
+            headers: Default::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Compares two URIs according to the rules of
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.4), unlike
+    /// `PartialEq` which compares each field literally:
+    ///
+    /// * scheme and host are compared ignoring case, while user and password are case-sensitive,
+    ///   with escaped characters being equal to their unescaped form
+    /// * a missing user or port never matches a present one
+    /// * params present in both URIs must match (check [Param::equivalent]), while a `transport`,
+    ///   `user`, `ttl`, `method` or `maddr` param present in only one URI never matches. Any
+    ///   other param present in only one URI is ignored
+    /// * headers must be present in both URIs, as many times and in any order, with names
+    ///   compared ignoring case and (unescaped) values compared exactly
+    pub fn equivalent(&self, other: &Uri) -> bool {
+        let schemes_match = match (&self.scheme, &other.scheme) {
+            (Some(scheme), Some(other)) => {
+                scheme.to_string().eq_ignore_ascii_case(&other.to_string())
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        let auths_match = match (&self.auth, &other.auth) {
+            (Some(auth), Some(other)) => auth.user == other.user && auth.password == other.password,
+            (None, None) => true,
+            _ => false,
+        };
+
+        schemes_match
+            && self.opaque == other.opaque
+            && auths_match
+            && self.host_with_port.equivalent(&other.host_with_port)
+            && params_equivalent(&self.params, &other.params)
+            && params_equivalent(&other.params, &self.params)
+            && headers_equivalent(&self.headers, &other.headers)
+    }
+
     pub fn is_sips(&self) -> Result<bool, Error> {
         Ok(self
             .scheme
@@ -63,8 +161,46 @@ impl Uri {
     }
 }
 
+//params that, according to RFC3261, never match when present in only one of the URIs
+const STRICT_PARAMS: [&str; 5] = ["transport", "user", "ttl", "method", "maddr"];
+
+fn params_equivalent(params: &[Param], others: &[Param]) -> bool {
+    params.iter().all(
+        |param| match others.iter().find(|other| other.same_name(param)) {
+            Some(other) => param.equivalent(other),
+            None => !STRICT_PARAMS
+                .iter()
+                .any(|name| param.name_value().0.eq_ignore_ascii_case(name)),
+        },
+    )
+}
+
+//headers are compared as a multiset: each header must match a different header of the other
+//URI, names ignoring case and (already percent-decoded) values exactly
+fn headers_equivalent(headers: &UriHeaders, others: &UriHeaders) -> bool {
+    let mut others = others.iter().map(Some).collect::<Vec<_>>();
+
+    headers.iter().all(|header| {
+        others
+            .iter_mut()
+            .find(|other| {
+                matches!(other, Some(other) if header.name.eq_ignore_ascii_case(&other.name)
+                    && header.value == other.value)
+            })
+            .and_then(Option::take)
+            .is_some()
+    }) && others.iter().all(Option::is_none)
+}
+
 impl std::fmt::Display for Uri {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(opaque) = &self.opaque {
+            return match &self.scheme {
+                Some(scheme) => write!(f, "{}:{}", scheme, opaque),
+                None => write!(f, "{}", opaque),
+            };
+        }
+
         let scheme = match &self.scheme {
             Some(Scheme::Other(scheme)) => format!("{}://", scheme),
             Some(scheme) => format!("{}:", scheme),
@@ -77,15 +213,16 @@ impl std::fmt::Display for Uri {
 
         write!(
             f,
-            "{}{}{}{}",
+            "{}{}{}{}{}",
             scheme,
             auth,
             self.host_with_port,
             self.params
                 .iter()
-                .map(|p| p.to_string())
+                .map(|p| p.to_uri_string())
                 .collect::<Vec<_>>()
-                .join("")
+                .join(""),
+            self.headers
         )
     }
 }
@@ -114,6 +251,7 @@ impl From<HostWithPort> for Uri {
             auth: None,
             params: Default::default(),
             headers: Default::default(),
+            opaque: None,
         }
     }
 }
@@ -126,6 +264,7 @@ impl From<(Scheme, Host)> for Uri {
             auth: None,
             params: Default::default(),
             headers: Default::default(),
+            opaque: None,
         }
     }
 }
@@ -142,6 +281,7 @@ where
             auth: None,
             params: Default::default(),
             headers: Default::default(),
+            opaque: None,
         }
     }
 }
@@ -154,6 +294,7 @@ impl From<Host> for Uri {
             auth: None,
             params: Default::default(),
             headers: Default::default(),
+            opaque: None,
         }
     }
 }
@@ -166,6 +307,7 @@ impl From<std::net::SocketAddr> for Uri {
             auth: None,
             params: Default::default(),
             headers: Default::default(),
+            opaque: None,
         }
     }
 }
@@ -178,6 +320,7 @@ impl From<std::net::IpAddr> for Uri {
             auth: None,
             params: Default::default(),
             headers: Default::default(),
+            opaque: None,
         }
     }
 }
@@ -186,6 +329,14 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Uri
     type Error = Error;
 
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
+        if let Some(opaque) = tokenizer.opaque {
+            return Ok(Self {
+                scheme: tokenizer.scheme.map(TryInto::try_into).transpose()?,
+                opaque: Some(opaque.into()),
+                ..Default::default()
+            });
+        }
+
         Ok(Self {
             scheme: tokenizer.scheme.map(TryInto::try_into).transpose()?,
             auth: tokenizer.auth.map(TryInto::try_into).transpose()?,
@@ -193,9 +344,16 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Uri
             params: tokenizer
                 .params
                 .into_iter()
-                .map(TryInto::try_into)
+                .map(|param| Param::try_from(param).map(Param::unescaped))
                 .collect::<Result<Vec<_>, _>>()?,
-            headers: Default::default(),
+            headers: tokenizer
+                .headers
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+            opaque: None,
         })
     }
 }
@@ -204,6 +362,14 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a [u8], u8>> for Uri {
     type Error = Error;
 
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a [u8], u8>) -> Result<Self, Self::Error> {
+        if let Some(opaque) = tokenizer.opaque {
+            return Ok(Self {
+                scheme: tokenizer.scheme.map(TryInto::try_into).transpose()?,
+                opaque: Some(std::str::from_utf8(opaque)?.into()),
+                ..Default::default()
+            });
+        }
+
         Ok(Self {
             scheme: tokenizer.scheme.map(TryInto::try_into).transpose()?,
             auth: tokenizer.auth.map(TryInto::try_into).transpose()?,
@@ -211,17 +377,24 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a [u8], u8>> for Uri {
             params: tokenizer
                 .params
                 .into_iter()
-                .map(TryInto::try_into)
+                .map(|param| Param::try_from(param).map(Param::unescaped))
                 .collect::<Result<Vec<_>, _>>()?,
-            headers: Default::default(),
+            headers: tokenizer
+                .headers
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+            opaque: None,
         })
     }
 }
 
 #[doc(hidden)]
 pub mod tokenizer {
-    use super::{auth, host_with_port, param, scheme};
-    use crate::{AbstractInput, AbstractInputItem, GResult, TokenizerError, uri};
+    use super::{auth, host_with_port, param, scheme, uri_header};
+    use crate::{AbstractInput, AbstractInputItem, GResult, TokenizerError};
     use std::marker::PhantomData;
 
     #[derive(Debug, PartialEq, Eq, Clone, Default)]
@@ -235,7 +408,8 @@ pub mod tokenizer {
         pub host_with_port: host_with_port::Tokenizer<'a, T, I>,
         pub params: Vec<param::Tokenizer<'a, T, I>>,
         //TODO: why option here?
-        pub headers: Option<Vec<T>>,
+        pub headers: Option<Vec<uri_header::Tokenizer<'a, T, I>>>,
+        pub opaque: Option<T>,
         pub phantom1: PhantomData<&'a T>,
         pub phantom2: PhantomData<I>,
     }
@@ -249,28 +423,15 @@ pub mod tokenizer {
         pub fn tokenize(part: T) -> GResult<T, Self> {
             use nom::{combinator::opt, multi::many0};
 
+            if let Ok(absolute) = Self::tokenize_absolute(part, " \t\r\n<>\"") {
+                return Ok(absolute);
+            }
 
             let (rem, scheme) = opt(scheme::Tokenizer::tokenize)(part)?;
-            match scheme {
-                None => {println!("auth is None")}
-                Some(_) => {println!("scheme: {}", scheme.clone().unwrap().value.into())}
-            }
             let (rem, auth) = opt(auth::Tokenizer::tokenize)(rem)?;
-            match auth {
-                None => {println!("auth is None")}
-                Some(_) => {println!("user: {}", auth.clone().unwrap().user.into())}
-            }
             let (rem, host_with_port) = host_with_port::Tokenizer::tokenize(rem)?;
-            println!("host: {}", host_with_port.clone().host.into());
-            match host_with_port.port {
-                None => {println!("port is None")}
-                Some(_) => {println!("port: {}", host_with_port.clone().port.unwrap().into())}
-            }
-            let (rem, params) = many0(param::Tokenizer::tokenize)(rem)?;
-            // for t in params.clone() {
-            //     println!("param name: {}, value: {}", t.name.into(), t.value.unwrap().into());
-            // }
-
+            let (rem, params) = many0(param::Tokenizer::tokenize_in_uri)(rem)?;
+            let (rem, headers) = opt(uri_header::Tokenizer::tokenize_all)(rem)?;
 
             Ok((
                 rem,
@@ -279,8 +440,8 @@ pub mod tokenizer {
                     auth,
                     host_with_port,
                     params,
-                    //TODO: support headers in the uri
-                    headers: None,
+                    headers,
+                    opaque: None,
                     phantom1: Default::default(),
                     phantom2: Default::default(),
                 },
@@ -290,6 +451,10 @@ pub mod tokenizer {
         pub fn tokenize_without_params(part: T) -> GResult<T, Self> {
             use nom::combinator::opt;
 
+            if let Ok(absolute) = Self::tokenize_absolute(part, " \t\r\n<>\";,?") {
+                return Ok(absolute);
+            }
+
             let (rem, scheme) = opt(scheme::Tokenizer::tokenize)(part)?;
             let (rem, auth) = opt(auth::Tokenizer::tokenize)(rem)?;
             let (rem, host_with_port) = host_with_port::Tokenizer::tokenize(rem)?;
@@ -302,12 +467,90 @@ pub mod tokenizer {
                     host_with_port,
                     params: vec![],
                     headers: None,
+                    opaque: None,
+                    phantom1: Default::default(),
+                    phantom2: Default::default(),
+                },
+            ))
+        }
+
+        //tokenizes an absolute URI of any non SIP-shaped scheme (RFC3986), keeping everything
+        //after the scheme as is, up to any of the given stopbreak chars.
+        //
+        //Since rsip accepts URIs without a scheme, `host:port` and `user:password@host` are
+        //ambiguous here: those are left to the SIP-shaped tokenizer, unless the scheme is a
+        //well known one that holds an `@` (like `mailto`) or it is followed by `//`. Digits that
+        //don't fit in a port (like `isbn:2983792873`) are not taken as one.
+        fn tokenize_absolute(part: T, stopbreaks: &'static str) -> GResult<T, Self> {
+            use nom::{
+                bytes::complete::{tag, take_till1, take_while1},
+                sequence::tuple,
+            };
+
+            let (rem, (name, _, opaque)) = tuple((
+                take_while1(|c: I| {
+                    let c = c.as_char();
+                    c.is_ascii_alphanumeric() || "+-.".contains(c)
+                }),
+                tag(":"),
+                take_till1(|c: I| stopbreaks.contains(c.as_char())),
+            ))(part)
+            .map_err(|_: nom::Err<TokenizerError>| {
+                TokenizerError::from(("absolute uri", part)).into()
+            })?;
+
+            let scheme = String::from_utf8_lossy(name.into()).to_string();
+            let opaque_chars = Into::<&bstr::BStr>::into(opaque)
+                .iter()
+                .map(|c| char::from(*c));
+            let port_len = opaque_chars
+                .clone()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            let is_port = port_len > 0
+                && opaque_chars
+                    .clone()
+                    .take(port_len)
+                    .collect::<String>()
+                    .parse::<u16>()
+                    .is_ok()
+                && opaque_chars
+                    .clone()
+                    .nth(port_len)
+                    .map(|c| ";?,/".contains(c))
+                    .unwrap_or(true);
+            let is_hierarchical = opaque_chars.clone().take(2).eq("//".chars());
+            let is_userinfo = opaque_chars.clone().any(|c| c == '@')
+                && !AT_SCHEMES.iter().any(|s| s.eq_ignore_ascii_case(&scheme));
+
+            if !scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                || SIP_SCHEMES.iter().any(|s| s.eq_ignore_ascii_case(&scheme))
+                || is_port
+                || (is_userinfo && !is_hierarchical)
+            {
+                return Err(TokenizerError::from(("absolute uri", part)).into());
+            }
+
+            Ok((
+                rem,
+                Self {
+                    scheme: Some(scheme::Tokenizer::from(name)),
+                    auth: None,
+                    host_with_port: (T::default(), None).into(),
+                    params: vec![],
+                    headers: None,
+                    opaque: Some(opaque),
                     phantom1: Default::default(),
                     phantom2: Default::default(),
                 },
             ))
         }
     }
+
+    //schemes tokenized in the SIP-shaped form
+    const SIP_SCHEMES: [&str; 3] = ["sip", "sips", "tel"];
+    //well known schemes whose URIs hold an `@`, which would otherwise be taken as userinfo
+    const AT_SCHEMES: [&str; 6] = ["mailto", "im", "pres", "xmpp", "cid", "mid"];
 }
 
 #[cfg(feature = "test-utils")]
@@ -315,12 +558,20 @@ impl testing_utils::Randomize for Uri {
     fn random() -> Self {
         use testing_utils::{opt, Randomize};
 
-        Self {
-            scheme: opt(Randomize::random()),
-            auth: opt(Randomize::random()),
-            host_with_port: Randomize::random(),
-            params: Randomize::rand_list0(3),
-            headers: vec![],
+        match opt(Scheme::random()) {
+            Some(Scheme::Other(scheme)) => Self {
+                scheme: Some(Scheme::Other(scheme)),
+                opaque: Some(testing_utils::rand_str_of(10)),
+                ..Default::default()
+            },
+            scheme => Self {
+                scheme,
+                auth: opt(Randomize::random()),
+                host_with_port: Randomize::random(),
+                params: Randomize::rand_list0(3),
+                headers: UriHeader::rand_list0(2).into(),
+                opaque: None,
+            },
         }
     }
 }
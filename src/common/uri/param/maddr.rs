@@ -1,13 +1,30 @@
+use crate::Host;
 use rsip_derives::{IntoParam, NewType};
+use std::net::IpAddr;
 
 /// Simple NewType around String. Intended to be used for the `maddr` parameter found in the
 /// `Via` header.
+///
+/// Since `maddr` holds a host, IPv6 addresses are expected inside brackets, which is what
+/// converting from an [IpAddr] gives.
 #[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
 pub struct Maddr(String);
 
+impl Maddr {
+    pub fn host(&self) -> Host {
+        self.0.as_str().into()
+    }
+}
+
+impl From<IpAddr> for Maddr {
+    fn from(ip_addr: IpAddr) -> Self {
+        Self(Host::from(ip_addr).to_string())
+    }
+}
+
 #[cfg(feature = "test-utils")]
 impl testing_utils::Randomize for Maddr {
     fn random() -> Self {
-        Self(std::net::IpAddr::random().to_string())
+        std::net::IpAddr::random().into()
     }
 }
@@ -19,7 +19,10 @@ pub use tag::Tag;
 pub use ttl::Ttl;
 pub use user::User;
 
-use crate::{Error, Method, Transport};
+use crate::{
+    utils::{percent_decode_lossy, percent_encode},
+    Error, Method, Transport,
+};
 use rsip_derives::NewType;
 use std::convert::TryInto;
 
@@ -47,9 +50,112 @@ pub enum Param {
 
 #[derive(NewType, Debug, PartialEq, Eq, Clone)]
 pub struct OtherParam(String);
+
+/// The value of a [Param::Other]. In header params it is kept as found, given that `%` is just
+/// another token character there. Inside a URI it is kept percent-decoded instead, unless it is
+/// a quoted string, in which case it is kept as is (quotes included). Values with malformed
+/// escapes (like a `%` not followed by 2 hex digits) are kept as is as well.
+///
+/// Unlike [Auth](super::Auth), the escaped form found in the URI is not kept: the value is
+/// escaped again when displayed, so needless escapes (like `%61` for `a`) are not written back.
 #[derive(NewType, Debug, PartialEq, Eq, Clone)]
 pub struct OtherParamValue(String);
 
+impl OtherParamValue {
+    /// Returns the value escaped as it should appear in a URI param, according to the param
+    /// characters of [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-25.1).
+    /// Quoted strings are returned as is.
+    pub fn escaped(&self) -> String {
+        if self.is_quoted() {
+            return self.0.clone();
+        }
+
+        //a `%` is escaped only if it could be mistaken for an escape sequence, this way
+        //values with malformed escapes are written back the way they were found
+        let mut escaped = String::with_capacity(self.0.len());
+        for (index, part) in self.0.split('%').enumerate() {
+            if index > 0 {
+                match part.get(..2) {
+                    Some(hex) if hex.bytes().all(|c| c.is_ascii_hexdigit()) => {
+                        escaped.push_str("%25")
+                    }
+                    _ => escaped.push('%'),
+                }
+            }
+            escaped.push_str(&percent_encode(part, is_param_char));
+        }
+
+        escaped
+    }
+
+    fn is_quoted(&self) -> bool {
+        self.0.len() > 1 && self.0.starts_with('"') && self.0.ends_with('"')
+    }
+}
+
+//param-unreserved and unreserved characters of RFC3261
+fn is_param_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "[]/:&+$-_.!~*'()".contains(c)
+}
+
+impl Param {
+    /// Compares two params according to the URI comparison rules of
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.4): both name and
+    /// value are compared ignoring case, and escaped characters are equal to their unescaped
+    /// form.
+    pub fn equivalent(&self, other: &Param) -> bool {
+        let (name, value) = self.name_value();
+        let (other_name, other_value) = other.name_value();
+
+        name.eq_ignore_ascii_case(&other_name)
+            && match (value, other_value) {
+                (Some(value), Some(other_value)) => percent_decode_lossy(&value)
+                    .to_lowercase()
+                    .eq(&percent_decode_lossy(&other_value).to_lowercase()),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+
+    /// Returns true if both params have the same name, ignoring case.
+    pub fn same_name(&self, other: &Param) -> bool {
+        self.name_value()
+            .0
+            .eq_ignore_ascii_case(&other.name_value().0)
+    }
+
+    /// Decodes the value of a [Param::Other] found inside a URI, check [OtherParamValue].
+    pub(crate) fn unescaped(self) -> Self {
+        match self {
+            Self::Other(name, Some(value)) if !value.is_quoted() => {
+                let value = percent_decode_lossy(&value.0);
+                Self::Other(name, Some(value.into()))
+            }
+            param => param,
+        }
+    }
+
+    /// Same as the [Display](std::fmt::Display) implementation, but escapes the value of a
+    /// [Param::Other], as expected inside a URI.
+    pub(crate) fn to_uri_string(&self) -> String {
+        match self {
+            Self::Other(name, Some(value)) => format!(";{}={}", name, value.escaped()),
+            param => param.to_string(),
+        }
+    }
+
+    //name and value as they appear in a URI
+    pub(crate) fn name_value(&self) -> (String, Option<String>) {
+        let param = self.to_uri_string();
+        let param = param.trim_start_matches(';');
+
+        match param.split_once('=') {
+            Some((name, value)) => (name.into(), Some(value.into())),
+            None => (param.into(), None),
+        }
+    }
+}
+
 impl std::fmt::Display for Param {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
@@ -159,34 +265,61 @@ pub mod tokenizer {
         T: AbstractInput<'a, I>,
         I: AbstractInputItem<I>,
     {
+        //whitespace around the semicolon and the equal sign is allowed (SEMI and EQUAL in
+        //RFC3261), so this is meant for header params, mostly seen after unfolding a header
         pub fn tokenize(part: T) -> GResult<T, Self> {
+            Self::tokenize_with_lws(part, true)
+        }
+
+        //params inside a URI can't have any whitespace (RFC3261 section 25.1)
+        pub fn tokenize_in_uri(part: T) -> GResult<T, Self> {
+            Self::tokenize_with_lws(part, false)
+        }
+
+        fn tokenize_with_lws(part: T, lws: bool) -> GResult<T, Self> {
             use nom::{
                 branch::alt,
                 bytes::complete::{tag, take_until, take_while},
-                combinator::{map, opt, recognize},
+                character::complete::space0,
+                combinator::{opt, recognize},
                 sequence::{delimited, tuple},
             };
 
-            let (rem, (_, name, value)) = tuple((
+            let (rem, (before_semi, _, after_semi, name, value)) = tuple((
+                space0,
                 tag(";"),
+                space0,
                 take_while(I::is_token), //rfc3261 includes other chars as well, needs fixing..
-                opt(map(
-                    tuple((
-                        tag("="),
-                        alt((
-                            recognize(delimited(tag("\""), take_until("\""), tag("\""))),
-                            // take_while(I::is_token),
-                            take_while(|c: I| return "[]/:&+$".contains(c.clone().as_char()) || I::is_token(c)),
-                        )),
+                opt(tuple((
+                    space0,
+                    tag("="),
+                    space0,
+                    alt((
+                        recognize(delimited(tag("\""), take_until("\""), tag("\""))),
+                        // take_while(I::is_token),
+                        take_while(|c: I| {
+                            return "[]/:&+$".contains(c.clone().as_char()) || I::is_token(c);
+                        }),
                     )),
-                    |t| t.1,
-                )),
+                ))),
             ))(part)
             .map_err(|_: GenericNomError<'a, T>| {
                 TokenizerError::from(("uri param", part)).into()
             })?;
 
-            Ok((rem, (name, value).into()))
+            let has_lws = [before_semi, after_semi]
+                .iter()
+                .chain(
+                    value
+                        .iter()
+                        .flat_map(|(before, _, after, _)| [before, after]),
+                )
+                .any(|spaces| !spaces.is_empty());
+            if !lws && has_lws {
+                return Err(TokenizerError::from(("uri param", part)).into());
+            }
+
+            Ok((rem, (name, value.map(|(_, _, _, value)| value)).into()))
         }
     }
 }
@@ -3,18 +3,33 @@ use std::net::IpAddr;
 
 /// Simple NewType around String. Intended to be used for the `received` parameter found in the `Via`
 /// header.
+///
+/// IPv6 addresses might be found both bare and inside brackets, as
+/// [RFC5118](https://datatracker.ietf.org/doc/html/rfc5118#section-4.5) notes. Both forms are
+/// accepted when parsing, while converting from an [IpAddr] always gives the bare form, as
+/// the `received` ABNF of [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-25.1)
+/// expects.
 #[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
 pub struct Received(String);
 
 impl Received {
     pub fn parse(&self) -> Result<IpAddr, std::net::AddrParseError> {
-        self.0.parse()
+        match self.0.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(ipv6) => ipv6.parse().map(IpAddr::V6),
+            None => self.0.parse(),
+        }
+    }
+}
+
+impl From<IpAddr> for Received {
+    fn from(ip_addr: IpAddr) -> Self {
+        Self(ip_addr.to_string())
     }
 }
 
 #[cfg(feature = "test-utils")]
 impl testing_utils::Randomize for Received {
     fn random() -> Self {
-        Self(std::net::IpAddr::random().to_string())
+        std::net::IpAddr::random().into()
     }
 }
@@ -25,6 +25,11 @@ impl Scheme {
         }
     }
 
+    /// Checks if this is either a SIP or a SIPS scheme.
+    pub fn is_sip(&self) -> bool {
+        matches!(self, Self::Sip | Self::Sips)
+    }
+
     pub fn is_sips(&self) -> Result<bool, Error> {
         match self {
             Self::Sip => Ok(false),
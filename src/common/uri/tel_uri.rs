@@ -0,0 +1,230 @@
+use crate::{
+    common::uri::{param::User, Host, HostWithPort, Param, Scheme, Uri},
+    Error, TokenizerError,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// A typed `tel` URI, as defined in [RFC3966](https://datatracker.ietf.org/doc/html/rfc3966).
+///
+/// A [Uri] parses a `tel` URI in the same shape as a SIP URI, with the number held as the host.
+/// This type can be created out of such a [Uri] (using `TryFrom`), or out of a SIP(S) [Uri] that
+/// carries a telephone number in its user part and a `user=phone` param, like
+/// `sip:+1-212-555-1212;ext=123@gateway.com;user=phone`.
+///
+/// The `number` (a global one, starting with `+`, or a local one) and the `ext` are kept with
+/// any visual separators (`-`, `.`, `(`, `)`) removed. A local number requires a `phone-context`,
+/// which is kept as is, unless it is a global number as well.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TelUri {
+    pub number: String,
+    pub phone_context: Option<String>,
+    pub ext: Option<String>,
+    pub isub: Option<String>,
+    pub params: Vec<Param>,
+}
+
+impl TelUri {
+    pub fn is_global(&self) -> bool {
+        self.number.starts_with('+')
+    }
+
+    /// Returns the `telephone-subscriber` part of the URI: the number followed by its params.
+    /// The `ext` and `isub` params come first, followed by the `phone-context` and any other
+    /// params, as [RFC3966](https://datatracker.ietf.org/doc/html/rfc3966#section-5.1.5)
+    /// suggests.
+    pub fn subscriber(&self) -> String {
+        let mut subscriber = self.number.clone();
+        if let Some(ext) = &self.ext {
+            subscriber.push_str(&format!(";ext={}", ext));
+        }
+        if let Some(isub) = &self.isub {
+            subscriber.push_str(&format!(";isub={}", isub));
+        }
+        if let Some(phone_context) = &self.phone_context {
+            subscriber.push_str(&format!(";phone-context={}", phone_context));
+        }
+        for param in self.params.iter() {
+            subscriber.push_str(&param.to_string());
+        }
+
+        subscriber
+    }
+
+    /// Converts the `tel` URI to a SIP URI pointing to the given host (usually a gateway), as
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.6) describes: the
+    /// whole `telephone-subscriber` goes to the user part and a `user=phone` param is added.
+    pub fn to_sip_uri<H: Into<HostWithPort>>(&self, host_with_port: H) -> Uri {
+        Uri {
+            scheme: Some(Scheme::Sip),
+            auth: Some((self.subscriber(), Option::<String>::None).into()),
+            host_with_port: host_with_port.into(),
+            params: vec![Param::User(User::new("phone"))],
+            headers: Default::default(),
+            opaque: None,
+        }
+    }
+}
+
+impl std::fmt::Display for TelUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tel:{}", self.subscriber())
+    }
+}
+
+impl From<TelUri> for Uri {
+    fn from(tel_uri: TelUri) -> Self {
+        let mut params = vec![];
+        if let Some(ext) = tel_uri.ext {
+            params.push(Param::Other("ext".into(), Some(ext.into())));
+        }
+        if let Some(isub) = tel_uri.isub {
+            params.push(Param::Other("isub".into(), Some(isub.into())));
+        }
+        if let Some(phone_context) = tel_uri.phone_context {
+            params.push(Param::Other(
+                "phone-context".into(),
+                Some(phone_context.into()),
+            ));
+        }
+        params.extend(tel_uri.params);
+
+        Self {
+            scheme: Some(Scheme::Tel),
+            auth: None,
+            host_with_port: Host::Domain(tel_uri.number.into()).into(),
+            params,
+            headers: Default::default(),
+            opaque: None,
+        }
+    }
+}
+
+impl TryFrom<&Uri> for TelUri {
+    type Error = Error;
+
+    fn try_from(uri: &Uri) -> Result<Self, Self::Error> {
+        match &uri.scheme {
+            Some(Scheme::Tel) => {
+                Self::try_from((uri.host_with_port.to_string(), uri.params.clone()))
+            }
+            Some(Scheme::Sip) | Some(Scheme::Sips) if is_phone(&uri.params) => {
+                let user = uri
+                    .user()
+                    .ok_or_else(|| Error::ParseError("tel uri: missing number".into()))?;
+                let (number, params) = match user.find(';') {
+                    Some(index) => user.split_at(index),
+                    None => (user, ""),
+                };
+
+                Self::try_from((number.to_string(), tokenize_params(params)?))
+            }
+            _ => Err(Error::ParseError(format!("not a tel uri: {}", uri))),
+        }
+    }
+}
+
+impl TryFrom<Uri> for TelUri {
+    type Error = Error;
+
+    fn try_from(uri: Uri) -> Result<Self, Self::Error> {
+        Self::try_from(&uri)
+    }
+}
+
+impl TryFrom<&str> for TelUri {
+    type Error = Error;
+
+    fn try_from(from: &str) -> Result<Self, Self::Error> {
+        Self::try_from(Uri::try_from(from)?)
+    }
+}
+
+impl TryFrom<(String, Vec<Param>)> for TelUri {
+    type Error = Error;
+
+    fn try_from((number, all_params): (String, Vec<Param>)) -> Result<Self, Self::Error> {
+        let number = strip_visual_separators(&number);
+        if !is_global_number(&number) && !is_local_number(&number) {
+            return Err(Error::ParseError(format!("invalid tel number: {}", number)));
+        }
+
+        let mut tel_uri = Self {
+            number,
+            phone_context: None,
+            ext: None,
+            isub: None,
+            params: vec![],
+        };
+
+        for param in all_params.into_iter() {
+            match param {
+                Param::Other(name, Some(value)) if name.to_string().eq_ignore_ascii_case("ext") => {
+                    let ext = strip_visual_separators(&value.to_string());
+                    match !ext.is_empty() && ext.chars().all(|c| c.is_ascii_digit()) {
+                        true => tel_uri.ext = Some(ext),
+                        false => return Err(Error::InvalidParam(format!("ext: {}", value))),
+                    }
+                }
+                Param::Other(name, Some(value))
+                    if name.to_string().eq_ignore_ascii_case("isub") =>
+                {
+                    tel_uri.isub = Some(value.to_string())
+                }
+                Param::Other(name, Some(value))
+                    if name.to_string().eq_ignore_ascii_case("phone-context") =>
+                {
+                    let value = value.to_string();
+                    tel_uri.phone_context = match value.starts_with('+') {
+                        true => Some(strip_visual_separators(&value)),
+                        false => Some(value),
+                    }
+                }
+                param => tel_uri.params.push(param),
+            }
+        }
+
+        match tel_uri.is_global() || tel_uri.phone_context.is_some() {
+            true => Ok(tel_uri),
+            false => Err(Error::missing_param("phone-context")),
+        }
+    }
+}
+
+fn is_phone(params: &[Param]) -> bool {
+    params.iter().any(|param| match param {
+        Param::User(user) => user.to_string().eq_ignore_ascii_case("phone"),
+        _ => false,
+    })
+}
+
+fn tokenize_params(part: &str) -> Result<Vec<Param>, Error> {
+    use crate::common::uri::param;
+    use nom::multi::many0;
+
+    let (rem, params) = many0(param::Tokenizer::tokenize)(part)?;
+    if !rem.is_empty() {
+        return Err(TokenizerError::from(("tel uri params", rem)).into());
+    }
+
+    params.into_iter().map(TryInto::try_into).collect()
+}
+
+fn strip_visual_separators(part: &str) -> String {
+    part.chars().filter(|c| !"-.()".contains(*c)).collect()
+}
+
+//global-number-digits of RFC3966, after removing visual separators
+fn is_global_number(number: &str) -> bool {
+    match number.strip_prefix('+') {
+        Some(digits) => !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    }
+}
+
+//local-number-digits of RFC3966, after removing visual separators
+fn is_local_number(number: &str) -> bool {
+    !number.is_empty()
+        && number
+            .chars()
+            .all(|c| c.is_ascii_hexdigit() || c == '*' || c == '#')
+}
@@ -0,0 +1,254 @@
+#[doc(hidden)]
+pub use tokenizer::Tokenizer;
+
+use crate::{
+    utils::{percent_decode, percent_encode},
+    Error, Header,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// A single header found in the headers part of a [Uri](super::Uri), like `Subject=hi` in
+/// `sip:bob@biloxi.com?Subject=hi&Priority=urgent`.
+///
+/// Both the name and the value are kept percent-decoded. Encoding takes place when the header
+/// is displayed, using the characters that
+/// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-25.1) allows unescaped
+/// (`hnv-unreserved` and `unreserved`). The escaped form found in the URI is not kept, so
+/// needless escapes (like `%68i` for `hi`) are not written back.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UriHeader {
+    pub name: String,
+    pub value: String,
+}
+
+impl UriHeader {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The special `body` header holds the message body instead of an actual header, according
+    /// to [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.5).
+    pub fn is_body(&self) -> bool {
+        self.name.eq_ignore_ascii_case("body")
+    }
+}
+
+impl<N, V> From<(N, V)> for UriHeader
+where
+    N: Into<String>,
+    V: Into<String>,
+{
+    fn from(from: (N, V)) -> Self {
+        Self {
+            name: from.0.into(),
+            value: from.1.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for UriHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}={}",
+            percent_encode(&self.name, is_hnv_char),
+            percent_encode(&self.value, is_hnv_char)
+        )
+    }
+}
+
+/// Simple NewType around `Vec<UriHeader>`, holding all the headers of a [Uri](super::Uri).
+///
+/// On display, headers are separated using `&` and the leading `?` is added, unless there are
+/// no headers at all.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct UriHeaders(Vec<UriHeader>);
+
+impl UriHeaders {
+    pub fn push(&mut self, header: UriHeader) {
+        self.0.push(header)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &UriHeader> {
+        self.0.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the value of the first header with the given name (ignoring case), if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+            .map(|header| header.value())
+    }
+}
+
+impl IntoIterator for UriHeaders {
+    type IntoIter = ::std::vec::IntoIter<Self::Item>;
+    type Item = UriHeader;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl From<Vec<UriHeader>> for UriHeaders {
+    fn from(headers: Vec<UriHeader>) -> Self {
+        Self(headers)
+    }
+}
+
+impl From<UriHeaders> for Vec<UriHeader> {
+    fn from(from: UriHeaders) -> Self {
+        from.0
+    }
+}
+
+impl std::fmt::Display for UriHeaders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            write!(f, "")
+        } else {
+            write!(
+                f,
+                "?{}",
+                self.iter()
+                    .map(|h| h.to_string())
+                    .collect::<Vec<_>>()
+                    .join("&")
+            )
+        }
+    }
+}
+
+/// Converts a URI header to an actual [Header], check
+/// [Uri::header_fields](super::Uri::header_fields).
+impl TryFrom<UriHeader> for Header {
+    type Error = Error;
+
+    fn try_from(from: UriHeader) -> Result<Self, Self::Error> {
+        crate::headers::header::Tokenizer::from((from.name.as_bytes(), from.value.as_bytes()))
+            .try_into()
+    }
+}
+
+impl<'a> TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for UriHeader {
+    type Error = Error;
+
+    fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: percent_decode(tokenizer.name)?,
+            value: percent_decode(tokenizer.value)?,
+        })
+    }
+}
+
+impl<'a> TryFrom<tokenizer::Tokenizer<'a, &'a [u8], u8>> for UriHeader {
+    type Error = Error;
+
+    fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a [u8], u8>) -> Result<Self, Self::Error> {
+        use std::str::from_utf8;
+
+        Self::try_from(Tokenizer::from((
+            from_utf8(tokenizer.name)?,
+            from_utf8(tokenizer.value)?,
+        )))
+    }
+}
+
+//hnv-unreserved and unreserved characters of RFC3261, escaped chars are accepted while
+//tokenizing as well
+pub(crate) fn is_hnv_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "[]/?:+$-_.!~*'()".contains(c)
+}
+
+#[doc(hidden)]
+pub mod tokenizer {
+    use crate::{AbstractInput, AbstractInputItem, GResult, GenericNomError, TokenizerError};
+    use std::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct Tokenizer<'a, T, I>
+    where
+        T: AbstractInput<'a, I>,
+        I: AbstractInputItem<I>,
+    {
+        pub name: T,
+        pub value: T,
+        phantom1: PhantomData<&'a T>,
+        phantom2: PhantomData<I>,
+    }
+
+    impl<'a, T, I> From<(T, T)> for Tokenizer<'a, T, I>
+    where
+        T: AbstractInput<'a, I>,
+        I: AbstractInputItem<I>,
+    {
+        fn from(from: (T, T)) -> Self {
+            Self {
+                name: from.0,
+                value: from.1,
+                phantom1: PhantomData,
+                phantom2: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, T, I> Tokenizer<'a, T, I>
+    where
+        T: AbstractInput<'a, I>,
+        I: AbstractInputItem<I>,
+        TokenizerError: nom::error::ParseError<T>,
+    {
+        //tokenizes all headers of a uri, including the leading `?`
+        pub fn tokenize_all(part: T) -> GResult<T, Vec<Self>> {
+            use nom::{bytes::complete::tag, multi::separated_list1, sequence::preceded};
+
+            preceded(tag("?"), separated_list1(tag("&"), Self::tokenize))(part).map_err(
+                |_: nom::Err<TokenizerError>| TokenizerError::from(("uri headers", part)).into(),
+            )
+        }
+
+        pub fn tokenize(part: T) -> GResult<T, Self> {
+            use nom::{
+                bytes::complete::{tag, take_while, take_while1},
+                sequence::tuple,
+            };
+
+            let is_hnv_char = |c: I| {
+                let c = c.as_char();
+                c == '%' || super::is_hnv_char(c)
+            };
+
+            let (rem, (name, _, value)) =
+                tuple((take_while1(is_hnv_char), tag("="), take_while(is_hnv_char)))(part)
+                    .map_err(|_: GenericNomError<'a, T>| {
+                        TokenizerError::from(("uri header", part)).into()
+                    })?;
+
+            Ok((rem, (name, value).into()))
+        }
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl testing_utils::Randomize for UriHeader {
+    fn random() -> Self {
+        use testing_utils::rand_str_of;
+
+        Self {
+            name: rand_str_of(5),
+            value: rand_str_of(8),
+        }
+    }
+}
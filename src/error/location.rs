@@ -0,0 +1,106 @@
+const SNIPPET_LEN: usize = 40;
+
+/// Where, in a SIP message, tokenizing failed.
+///
+/// * `offset` is the byte offset from the beginning of the message.
+/// * `line` and `column` are 1-based, with the column counted in bytes.
+/// * `header` is the name of the header that was being tokenized, if the failure is inside a
+///   header line (a folded header line counts as part of the header it continues).
+/// * `expected` is the element the tokenizer was trying to find, like `headers` or `scheme`.
+/// * `snippet` holds the message from the failing byte up to the end of its line (at most 40
+///   bytes of it).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ErrorLocation {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub header: Option<String>,
+    pub expected: Option<String>,
+    pub snippet: String,
+}
+
+impl ErrorLocation {
+    /// Finds the location of the given offset in the message. Returns `None` if the offset
+    /// lies outside the message.
+    pub fn new(input: &[u8], offset: usize, expected: Option<String>) -> Option<Self> {
+        if offset > input.len() {
+            return None;
+        }
+
+        let line_start = input[..offset]
+            .iter()
+            .rposition(|c| *c == b'\n')
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let line = input[..offset].iter().filter(|c| **c == b'\n').count() + 1;
+
+        let snippet = &input[offset..];
+        let snippet = &snippet[..line_end(snippet).min(SNIPPET_LEN)];
+
+        Some(Self {
+            offset,
+            line,
+            column: offset - line_start + 1,
+            header: header_name(input, line_start),
+            expected,
+            snippet: String::from_utf8_lossy(snippet).into_owned(),
+        })
+    }
+}
+
+impl std::fmt::Display for ErrorLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)?;
+        if let Some(header) = &self.header {
+            write!(f, " (header {})", header)?;
+        }
+        if let Some(expected) = &self.expected {
+            write!(f, ", expected {}", expected)?;
+        }
+        write!(f, ", near `{}`", self.snippet)
+    }
+}
+
+fn line_end(part: &[u8]) -> usize {
+    part.iter()
+        .position(|c| *c == b'\r' || *c == b'\n')
+        .unwrap_or(part.len())
+}
+
+//the name of the header that the line starting at `line_start` belongs to, walking back over
+//folded lines. The first line is the start line and anything after an empty line is the body.
+fn header_name(input: &[u8], mut line_start: usize) -> Option<String> {
+    loop {
+        if line_start == 0 || is_past_headers(&input[..line_start]) {
+            return None;
+        }
+
+        let line = &input[line_start..];
+        match line.first() {
+            Some(b' ') | Some(b'\t') => {
+                line_start = input[..line_start - 1]
+                    .iter()
+                    .rposition(|c| *c == b'\n')
+                    .map(|index| index + 1)
+                    .unwrap_or(0);
+            }
+            _ => {
+                let line = &line[..line_end(line)];
+                let name = line
+                    .iter()
+                    .position(|c| *c == b':' || *c == b' ' || *c == b'\t')
+                    .map(|index| &line[..index])
+                    .unwrap_or(line);
+
+                return match name.is_empty() {
+                    true => None,
+                    false => Some(String::from_utf8_lossy(name).into_owned()),
+                };
+            }
+        }
+    }
+}
+
+fn is_past_headers(part: &[u8]) -> bool {
+    part.windows(4).any(|window| window == b"\r\n\r\n")
+}
@@ -1,50 +1,144 @@
+mod location;
 mod tokenizer_error;
 
+pub use location::ErrorLocation;
 pub use tokenizer_error::TokenizerError;
 
-use std::{error::Error as StdError, fmt};
+use crate::message::Limit;
+use std::{error::Error as StdError, fmt, ops::Deref};
 
 /// The `Error` enum indicates that something went wrong
 ///
-/// It has 6 variants:
+/// Its variants are:
 ///
 /// * `MissingHeader` that a header that is expected to be found was completely missing.
-/// There are some headers that are required everywhere in SIP, like `From`, `To` etc.
+///   There are some headers that are required everywhere in SIP, like `From`, `To` etc.
+/// * `MissingParam` that a parameter that is expected to be found in a header was missing.
 /// * `InvalidParam` means some header parser did not succeed, and the reason for it is
-/// a missing or invalid parameter. Inner `String` should have the nom verbose error about it.
-/// * `ParseError` indicates a general parsing error. Inner `String` should have the verbose nom error
-/// giving hints on what went wrong.
-/// * `TokenizeError` indicates a general tokenizer error. Inner `String` should have the verbose nom error
-/// giving hints on what went wrong. A Tokenizer just tries to break the `bytes` into
-/// parts/components of rsip structs, but never tries to parse/convert to a specific type.
-/// So if a tokenizer fails and you get this error, it means it couldn't even manage to break the
-/// SIP message in the correct breakpoints.
-/// * `Utf8Error` indicates that the `from_utf8` std method completely failed. At least you should
-/// get the information regarding which header had this issue.
+///   a missing or invalid parameter. Inner `String` should have the nom verbose error about it.
+/// * `ParseError` indicates a general parsing error. Inner `String` should have the verbose nom
+///   error giving hints on what went wrong.
+/// * `TokenizeError` indicates a general tokenizer error. Inner `String` should have the verbose
+///   nom error giving hints on what went wrong. A Tokenizer just tries to break the `bytes` into
+///   parts/components of rsip structs, but never tries to parse/convert to a specific type.
+///   So if a tokenizer fails and you get this error, it means it couldn't even manage to break
+///   the SIP message in the correct breakpoints. When a whole message is parsed, it also carries
+///   the [ErrorLocation] of the failure (check [Error::location]).
+/// * `Utf8Error` indicates that the `from_utf8` std method completely failed. At least you
+///   should get the information regarding which header had this issue.
+/// * `LimitExceeded` indicates that a message exceeded one of the resource
+///   [Limits](crate::message::Limits) while parsing, along with the maximum allowed value.
 /// * `Unexpected` indicates any other error.
 ///
+/// Use [Error::kind] to match on the class of the failure, regardless of the variant's content.
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum Error {
     MissingHeader(String),
     MissingParam(String),
     InvalidParam(String),
     ParseError(String),
-    TokenizeError(String),
+    TokenizeError(TokenizeDetails),
     Utf8Error(String),
+    LimitExceeded(Limit, usize),
     Unexpected(String),
 }
 
+/// The class of an [Error], one for each of its variants.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum ErrorKind {
+    MissingHeader,
+    MissingParam,
+    InvalidParam,
+    Parse,
+    Tokenize,
+    Utf8,
+    LimitExceeded,
+    Unexpected,
+}
+
+/// The content of [Error::TokenizeError]: the tokenizer context, along with the location of the
+/// failure, if known. It dereferences to the context, so it can be used as a `str`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TokenizeDetails {
+    pub context: String,
+    pub location: Option<ErrorLocation>,
+}
+
+impl Deref for TokenizeDetails {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.context
+    }
+}
+
+impl From<String> for TokenizeDetails {
+    fn from(context: String) -> Self {
+        Self {
+            context,
+            location: None,
+        }
+    }
+}
+
+impl From<&str> for TokenizeDetails {
+    fn from(context: &str) -> Self {
+        context.to_string().into()
+    }
+}
+
+impl fmt::Display for TokenizeDetails {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} (at {})", self.context, location),
+            None => write!(f, "{}", self.context),
+        }
+    }
+}
+
 impl Error {
     pub fn tokenizer<'a, S, T>(tuple: (S, T)) -> Self
     where
         S: Into<String>,
         T: Into<&'a bstr::BStr>,
     {
-        Self::TokenizeError(format!(
-            "failed to tokenize {}: {}",
-            tuple.0.into(),
-            tuple.1.into()
-        ))
+        Self::TokenizeError(
+            format!("failed to tokenize {}: {}", tuple.0.into(), tuple.1.into()).into(),
+        )
+    }
+
+    /// Converts a tokenizer error to an [Error], locating the failure in `input`, which should
+    /// be the whole input that was given to the tokenizer.
+    pub fn located(error: nom::Err<TokenizerError>, input: &[u8]) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::TokenizeError(TokenizeDetails {
+                location: e.location(input),
+                context: e.context,
+            }),
+            error => error.into(),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::MissingHeader(_) => ErrorKind::MissingHeader,
+            Self::MissingParam(_) => ErrorKind::MissingParam,
+            Self::InvalidParam(_) => ErrorKind::InvalidParam,
+            Self::ParseError(_) => ErrorKind::Parse,
+            Self::TokenizeError(_) => ErrorKind::Tokenize,
+            Self::Utf8Error(_) => ErrorKind::Utf8,
+            Self::LimitExceeded(_, _) => ErrorKind::LimitExceeded,
+            Self::Unexpected(_) => ErrorKind::Unexpected,
+        }
+    }
+
+    /// The location of the failure in the message, available for tokenizer errors of whole
+    /// messages.
+    pub fn location(&self) -> Option<&ErrorLocation> {
+        match self {
+            Self::TokenizeError(details) => details.location.as_ref(),
+            _ => None,
+        }
     }
 
     pub fn missing_header(header: &'static str) -> Self {
@@ -58,7 +152,7 @@ impl Error {
 
 impl From<TokenizerError> for Error {
     fn from(from: TokenizerError) -> Self {
-        Self::TokenizeError(from.context)
+        Self::TokenizeError(from.context.into())
     }
 }
 
@@ -82,6 +176,9 @@ impl fmt::Display for Error {
             Self::TokenizeError(inner) => write!(f, "Tokenizer error: {}", inner),
             Self::Unexpected(inner) => write!(f, "rsip quite unexpected error: {}", inner),
             Self::Utf8Error(inner) => write!(f, "rsip error: invalid utf8 ({})", inner),
+            Self::LimitExceeded(limit, max) => {
+                write!(f, "rsip error: limit exceeded: {} (max {})", limit, max)
+            }
         }
     }
 }
@@ -99,3 +196,15 @@ impl From<std::num::ParseIntError> for Error {
         Self::ParseError(error.to_string())
     }
 }
+
+impl From<std::convert::Infallible> for Error {
+    fn from(error: std::convert::Infallible) -> Self {
+        match error {}
+    }
+}
+
+impl From<std::net::AddrParseError> for Error {
+    fn from(error: std::net::AddrParseError) -> Self {
+        Self::ParseError(error.to_string())
+    }
+}
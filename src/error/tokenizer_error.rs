@@ -1,18 +1,49 @@
+use super::ErrorLocation;
 use std::{error::Error as StdError, fmt};
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+/// The error of all tokenizers.
+///
+/// Apart from the `context`, it keeps the element that the tokenizer expected (if known) and
+/// the position of the input it failed at, so that the failure can be located in the whole
+/// message using [TokenizerError::location]. Two errors are equal when their `context` is equal.
+#[derive(Debug, Clone)]
 pub struct TokenizerError {
     pub context: String,
+    pub expected: Option<String>,
+    //address of the input part the tokenizer failed at
+    at: Option<usize>,
 }
 
+impl TokenizerError {
+    /// Locates the failure in `input`, which should be the whole input given to the
+    /// tokenizer. Returns `None` if the failure position is not known or is not part of `input`.
+    pub fn location(&self, input: &[u8]) -> Option<ErrorLocation> {
+        let offset = self.at?.checked_sub(input.as_ptr() as usize)?;
+
+        ErrorLocation::new(input, offset, self.expected.clone())
+    }
+}
+
+impl PartialEq for TokenizerError {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+}
+
+impl Eq for TokenizerError {}
+
 impl<'a, S, T> From<(S, T)> for TokenizerError
 where
     S: Into<String>,
     T: Into<&'a bstr::BStr>,
 {
     fn from(from: (S, T)) -> Self {
+        let (expected, part) = (from.0.into(), from.1.into());
+
         Self {
-            context: format!("failed to tokenize {}: {}", from.0.into(), from.1.into()),
+            context: format!("failed to tokenize {}: {}", expected, part),
+            expected: Some(expected),
+            at: Some(address(part)),
         }
     }
 }
@@ -21,6 +52,8 @@ impl From<&'static str> for TokenizerError {
     fn from(from: &'static str) -> Self {
         Self {
             context: from.into(),
+            expected: None,
+            at: None,
         }
     }
 }
@@ -49,8 +82,12 @@ impl StdError for TokenizerError {}
 
 impl<'a, T: Into<&'a bstr::BStr>> nom::error::ParseError<T> for TokenizerError {
     fn from_error_kind(input: T, kind: nom::error::ErrorKind) -> Self {
+        let input = input.into();
+
         Self {
-            context: format!("could not tokenize ({:?}): {}", kind, input.into()),
+            context: format!("could not tokenize ({:?}): {}", kind, input),
+            expected: None,
+            at: Some(address(input)),
         }
     }
     fn append(input: T, kind: nom::error::ErrorKind, other: Self) -> Self {
@@ -61,18 +98,36 @@ impl<'a, T: Into<&'a bstr::BStr>> nom::error::ParseError<T> for TokenizerError {
                 kind,
                 input.into(),
             ),
+            ..other
         }
     }
 
     fn from_char(input: T, c: char) -> Self {
+        let input = input.into();
+
         Self {
-            context: format!("was expecting char {} in: {}", c, input.into()),
+            context: format!("was expecting char {} in: {}", c, input),
+            expected: Some(c.to_string()),
+            at: Some(address(input)),
         }
     }
 
+    //keeps the position of the branch that went further into the input
     fn or(self, other: Self) -> Self {
+        let context = format!("tokenizer error: {} or {}", self, other.context);
+        let (expected, at) = match self.at > other.at {
+            true => (self.expected, self.at),
+            false => (other.expected, other.at),
+        };
+
         Self {
-            context: format!("tokenizer error: {} or {}", self, other.context),
+            context,
+            expected,
+            at,
         }
     }
 }
+
+fn address(part: &bstr::BStr) -> usize {
+    part.as_ptr() as usize
+}
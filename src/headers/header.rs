@@ -8,6 +8,20 @@ pub use tokenizer::Tokenizer;
 ///
 /// In case a header is not defined in Rsip, parsing will store it in the `Other`
 /// variant, which is a tuple of Strings.
+///
+/// A header whose value is not valid UTF-8 (like a Latin-1 display name sent by some legacy
+/// PBXs) is stored in the `NonUtf8` variant, which holds the name and the raw value (folded
+/// lines are kept as is), instead of failing the whole message. Its `Display` replaces the
+/// invalid bytes, but writing the message using [write_to](crate::SipMessage::write_to) (or
+/// converting it to bytes) writes the value back unchanged.
+///
+/// During parsing, compact header names (like `i` for `Call-ID` or `v` for `Via`) are mapped to
+/// the relevant variant as well. Use [Header::to_compact_string] if you want to write them back
+/// in their compact form.
+///
+/// Headers that the application has registered in a [HeaderRegistry](super::HeaderRegistry)
+/// are held in the `Custom` variant instead, when parsing through the registry.
+///
 /// For instance, constructing the `X-Fs-Sending-Message` header (related to SMS in SIP),
 /// you can do:
 /// ```
@@ -20,6 +34,7 @@ pub enum Header {
     AcceptLanguage(AcceptLanguage),
     AlertInfo(AlertInfo),
     Allow(Allow),
+    AllowEvents(AllowEvents),
     AuthenticationInfo(AuthenticationInfo),
     Authorization(Authorization),
     CSeq(CSeq),
@@ -31,6 +46,7 @@ pub enum Header {
     ContentLanguage(ContentLanguage),
     ContentLength(ContentLength),
     ContentType(ContentType),
+    Custom(super::Custom),
     Date(Date),
     ErrorInfo(ErrorInfo),
     Event(Event),
@@ -40,21 +56,33 @@ pub enum Header {
     MaxForwards(MaxForwards),
     MimeVersion(MimeVersion),
     MinExpires(MinExpires),
+    MinSe(MinSe),
+    NonUtf8(String, Vec<u8>),
     Organization(Organization),
     Other(String, String),
+    PAssertedIdentity(PAssertedIdentity),
+    PPreferredIdentity(PPreferredIdentity),
     Priority(Priority),
+    Privacy(Privacy),
     ProxyAuthenticate(ProxyAuthenticate),
     ProxyAuthorization(ProxyAuthorization),
     ProxyRequire(ProxyRequire),
+    RAck(RAck),
+    RSeq(RSeq),
     RecordRoute(RecordRoute),
+    ReferTo(ReferTo),
+    ReferredBy(ReferredBy),
+    Replaces(Replaces),
     ReplyTo(ReplyTo),
     Require(Require),
     RetryAfter(RetryAfter),
     Route(Route),
     Server(Server),
+    SessionExpires(SessionExpires),
     Subject(Subject),
     SubscriptionState(SubscriptionState),
     Supported(Supported),
+    TargetDialog(TargetDialog),
     Timestamp(Timestamp),
     To(To),
     Unsupported(Unsupported),
@@ -64,6 +92,119 @@ pub enum Header {
     WwwAuthenticate(WwwAuthenticate),
 }
 
+impl Header {
+    /// Returns the compact form of the header name, as defined in
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-7.3.3) and later
+    /// extensions, if the header has one.
+    pub fn compact_name(&self) -> Option<&'static str> {
+        self.compact().map(|(name, _)| name)
+    }
+
+    /// Same as `to_string` but uses the compact form of the header name, if there is one.
+    /// Useful when you need to squeeze a message under the UDP MTU.
+    pub fn to_compact_string(&self) -> String {
+        match self.compact() {
+            Some((name, value)) => format!("{}: {}", name, value),
+            None => self.to_string(),
+        }
+    }
+
+    /// Checks if the header value is a comma-separated list of values, which, according to
+    /// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-7.3.1), can be either
+    /// combined in a single header line or spread across multiple ones (like `Via`, `Contact`,
+    /// `Route` or `Allow`).
+    pub fn is_multi_value(&self) -> bool {
+        self.multi_value().is_some()
+    }
+
+    /// Splits a multi-value header (check [Header::is_multi_value]) to one header per value.
+    /// Commas inside quoted strings or angle brackets are not taken into account. Any other
+    /// header is returned as is.
+    pub fn split(&self) -> Vec<Header> {
+        match self.multi_value() {
+            Some(value) => crate::utils::split_values(value)
+                .into_iter()
+                .map(|value| self.with_value(value.into()))
+                .collect(),
+            None => vec![self.clone()],
+        }
+    }
+
+    fn compact(&self) -> Option<(&'static str, &str)> {
+        use crate::headers::untyped::UntypedHeader;
+
+        match self {
+            Self::AllowEvents(inner) => Some(("u", inner.value())),
+            Self::CallId(inner) => Some(("i", inner.value())),
+            Self::Contact(inner) => Some(("m", inner.value())),
+            Self::Custom(inner) => inner.compact_name().map(|name| (name, inner.value())),
+            Self::ContentEncoding(inner) => Some(("e", inner.value())),
+            Self::ContentLength(inner) => Some(("l", inner.value())),
+            Self::ContentType(inner) => Some(("c", inner.value())),
+            Self::Event(inner) => Some(("o", inner.value())),
+            Self::From(inner) => Some(("f", inner.value())),
+            Self::ReferTo(inner) => Some(("r", inner.value())),
+            Self::ReferredBy(inner) => Some(("b", inner.value())),
+            Self::SessionExpires(inner) => Some(("x", inner.value())),
+            Self::Subject(inner) => Some(("s", inner.value())),
+            Self::Supported(inner) => Some(("k", inner.value())),
+            Self::To(inner) => Some(("t", inner.value())),
+            Self::Via(inner) => Some(("v", inner.value())),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! multi_value_headers {
+    ($($variant:ident),*) => {
+        impl Header {
+            pub(crate) fn multi_value(&self) -> Option<&str> {
+                use crate::headers::untyped::UntypedHeader;
+
+                match self {
+                    $(Self::$variant(inner) => Some(inner.value()),)*
+                    _ => None,
+                }
+            }
+
+            //same kind of header, holding the given value, meant for multi-value headers only
+            pub(crate) fn with_value(&self, value: String) -> Self {
+                use crate::headers::untyped::UntypedHeader;
+
+                match self {
+                    $(Self::$variant(_) => Self::$variant($variant::new(value)),)*
+                    _ => self.clone(),
+                }
+            }
+        }
+    };
+}
+
+multi_value_headers!(
+    Accept,
+    AcceptEncoding,
+    AcceptLanguage,
+    AlertInfo,
+    Allow,
+    AllowEvents,
+    CallInfo,
+    Contact,
+    ContentEncoding,
+    ContentLanguage,
+    ErrorInfo,
+    InReplyTo,
+    PAssertedIdentity,
+    PPreferredIdentity,
+    ProxyRequire,
+    RecordRoute,
+    Require,
+    Route,
+    Supported,
+    Unsupported,
+    Via,
+    Warning
+);
+
 impl std::fmt::Display for Header {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -72,6 +213,7 @@ impl std::fmt::Display for Header {
             Self::AcceptLanguage(inner) => write!(f, "{}", inner),
             Self::AlertInfo(inner) => write!(f, "{}", inner),
             Self::Allow(inner) => write!(f, "{}", inner),
+            Self::AllowEvents(inner) => write!(f, "{}", inner),
             Self::AuthenticationInfo(inner) => write!(f, "{}", inner),
             Self::Authorization(inner) => write!(f, "{}", inner),
             Self::CSeq(inner) => write!(f, "{}", inner),
@@ -83,6 +225,7 @@ impl std::fmt::Display for Header {
             Self::ContentLanguage(inner) => write!(f, "{}", inner),
             Self::ContentLength(inner) => write!(f, "{}", inner),
             Self::ContentType(inner) => write!(f, "{}", inner),
+            Self::Custom(inner) => write!(f, "{}", inner),
             Self::Date(inner) => write!(f, "{}", inner),
             Self::ErrorInfo(inner) => write!(f, "{}", inner),
             Self::Event(inner) => write!(f, "{}", inner),
@@ -92,21 +235,35 @@ impl std::fmt::Display for Header {
             Self::MaxForwards(inner) => write!(f, "{}", inner),
             Self::MimeVersion(inner) => write!(f, "{}", inner),
             Self::MinExpires(inner) => write!(f, "{}", inner),
+            Self::MinSe(inner) => write!(f, "{}", inner),
+            Self::NonUtf8(key, value) => {
+                write!(f, "{}: {}", key, String::from_utf8_lossy(value))
+            }
             Self::Organization(inner) => write!(f, "{}", inner),
             Self::Other(key, value) => write!(f, "{}: {}", key, value),
+            Self::PAssertedIdentity(inner) => write!(f, "{}", inner),
+            Self::PPreferredIdentity(inner) => write!(f, "{}", inner),
             Self::Priority(inner) => write!(f, "{}", inner),
+            Self::Privacy(inner) => write!(f, "{}", inner),
             Self::ProxyAuthenticate(inner) => write!(f, "{}", inner),
             Self::ProxyAuthorization(inner) => write!(f, "{}", inner),
             Self::ProxyRequire(inner) => write!(f, "{}", inner),
+            Self::RAck(inner) => write!(f, "{}", inner),
+            Self::RSeq(inner) => write!(f, "{}", inner),
             Self::RecordRoute(inner) => write!(f, "{}", inner),
+            Self::ReferTo(inner) => write!(f, "{}", inner),
+            Self::ReferredBy(inner) => write!(f, "{}", inner),
+            Self::Replaces(inner) => write!(f, "{}", inner),
             Self::ReplyTo(inner) => write!(f, "{}", inner),
             Self::Require(inner) => write!(f, "{}", inner),
             Self::RetryAfter(inner) => write!(f, "{}", inner),
             Self::Route(inner) => write!(f, "{}", inner),
             Self::Server(inner) => write!(f, "{}", inner),
+            Self::SessionExpires(inner) => write!(f, "{}", inner),
             Self::Subject(inner) => write!(f, "{}", inner),
             Self::SubscriptionState(inner) => write!(f, "{}", inner),
             Self::Supported(inner) => write!(f, "{}", inner),
+            Self::TargetDialog(inner) => write!(f, "{}", inner),
             Self::Timestamp(inner) => write!(f, "{}", inner),
             Self::To(inner) => write!(f, "{}", inner),
             Self::Unsupported(inner) => write!(f, "{}", inner),
@@ -123,143 +280,189 @@ pub mod tokenizer {
     use super::*;
     use crate::{Error, IResult, TokenizerError};
     use rsip_derives::Utf8Tokenizer;
-    use std::convert::TryInto;
+    use std::{borrow::Cow, convert::TryInto};
 
     impl<'a> TryInto<Header> for Tokenizer<'a> {
         type Error = Error;
 
         fn try_into(self) -> Result<Header, Error> {
+            if std::str::from_utf8(self.value).is_err() {
+                return Ok(Header::NonUtf8(
+                    String::from_utf8_lossy(self.name).into(),
+                    self.value.to_vec(),
+                ));
+            }
+
             let tokenizer: Utf8Tokenizer = self.try_into()?;
+            let value = unfold(tokenizer.value);
 
             match tokenizer.name {
-                s if s.eq_ignore_ascii_case("Accept") => {
-                    Ok(Header::Accept(Accept::new(tokenizer.value)))
-                }
+                s if s.eq_ignore_ascii_case("Accept") => Ok(Header::Accept(Accept::new(value))),
                 s if s.eq_ignore_ascii_case("Accept-Encoding") => {
-                    Ok(Header::AcceptEncoding(AcceptEncoding::new(tokenizer.value)))
+                    Ok(Header::AcceptEncoding(AcceptEncoding::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Accept-Language") => {
-                    Ok(Header::AcceptLanguage(AcceptLanguage::new(tokenizer.value)))
+                    Ok(Header::AcceptLanguage(AcceptLanguage::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Alert-Info") => {
-                    Ok(Header::AlertInfo(AlertInfo::new(tokenizer.value)))
+                    Ok(Header::AlertInfo(AlertInfo::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Allow") => {
-                    Ok(Header::Allow(Allow::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Allow") => Ok(Header::Allow(Allow::new(value))),
+                s if s.eq_ignore_ascii_case("Allow-Events") || s.eq_ignore_ascii_case("u") => {
+                    Ok(Header::AllowEvents(AllowEvents::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Authentication-Info") => {
+                    Ok(Header::AuthenticationInfo(AuthenticationInfo::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Authentication-Info") => Ok(
-                    Header::AuthenticationInfo(AuthenticationInfo::new(tokenizer.value)),
-                ),
                 s if s.eq_ignore_ascii_case("Authorization") => {
-                    Ok(Header::Authorization(Authorization::new(tokenizer.value)))
+                    Ok(Header::Authorization(Authorization::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("CSeq") => Ok(Header::CSeq(CSeq::new(tokenizer.value))),
-                s if s.eq_ignore_ascii_case("Call-Id") => {
-                    Ok(Header::CallId(CallId::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("CSeq") => Ok(Header::CSeq(CSeq::new(value))),
+                s if s.eq_ignore_ascii_case("Call-Id") || s.eq_ignore_ascii_case("i") => {
+                    Ok(Header::CallId(CallId::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Call-Info") => {
-                    Ok(Header::CallInfo(CallInfo::new(tokenizer.value)))
+                    Ok(Header::CallInfo(CallInfo::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Contact") => {
-                    Ok(Header::Contact(Contact::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Contact") || s.eq_ignore_ascii_case("m") => {
+                    Ok(Header::Contact(Contact::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Content-Disposition") => Ok(
-                    Header::ContentDisposition(ContentDisposition::new(tokenizer.value)),
-                ),
-                s if s.eq_ignore_ascii_case("Content-Encoding") => Ok(Header::ContentEncoding(
-                    ContentEncoding::new(tokenizer.value),
-                )),
-                s if s.eq_ignore_ascii_case("Content-Language") => Ok(Header::ContentLanguage(
-                    ContentLanguage::new(tokenizer.value),
-                )),
-                s if s.eq_ignore_ascii_case("Content-Length") => {
-                    Ok(Header::ContentLength(ContentLength::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Content-Disposition") => {
+                    Ok(Header::ContentDisposition(ContentDisposition::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Content-Encoding") || s.eq_ignore_ascii_case("e") => {
+                    Ok(Header::ContentEncoding(ContentEncoding::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Content-Language") => {
+                    Ok(Header::ContentLanguage(ContentLanguage::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Content-Length") || s.eq_ignore_ascii_case("l") => {
+                    Ok(Header::ContentLength(ContentLength::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Content-Type") => {
-                    Ok(Header::ContentType(ContentType::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Content-Type") || s.eq_ignore_ascii_case("c") => {
+                    Ok(Header::ContentType(ContentType::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Date") => Ok(Header::Date(Date::new(tokenizer.value))),
+                s if s.eq_ignore_ascii_case("Date") => Ok(Header::Date(Date::new(value))),
                 s if s.eq_ignore_ascii_case("Error-Info") => {
-                    Ok(Header::ErrorInfo(ErrorInfo::new(tokenizer.value)))
+                    Ok(Header::ErrorInfo(ErrorInfo::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Event") => {
-                    Ok(Header::Event(Event::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Event") || s.eq_ignore_ascii_case("o") => {
+                    Ok(Header::Event(Event::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Expires") => {
-                    Ok(Header::Expires(Expires::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Expires") => Ok(Header::Expires(Expires::new(value))),
+                s if s.eq_ignore_ascii_case("From") || s.eq_ignore_ascii_case("f") => {
+                    Ok(Header::From(From::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("From") => Ok(Header::From(From::new(tokenizer.value))),
                 s if s.eq_ignore_ascii_case("In-Reply-To") => {
-                    Ok(Header::InReplyTo(InReplyTo::new(tokenizer.value)))
+                    Ok(Header::InReplyTo(InReplyTo::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Max-Forwards") => {
-                    Ok(Header::MaxForwards(MaxForwards::new(tokenizer.value)))
+                    Ok(Header::MaxForwards(MaxForwards::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Mime-Version") => {
-                    Ok(Header::MimeVersion(MimeVersion::new(tokenizer.value)))
+                    Ok(Header::MimeVersion(MimeVersion::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Min-Expires") => {
-                    Ok(Header::MinExpires(MinExpires::new(tokenizer.value)))
+                    Ok(Header::MinExpires(MinExpires::new(value)))
                 }
+                s if s.eq_ignore_ascii_case("Min-SE") => Ok(Header::MinSe(MinSe::new(value))),
                 s if s.eq_ignore_ascii_case("Organization") => {
-                    Ok(Header::Organization(Organization::new(tokenizer.value)))
+                    Ok(Header::Organization(Organization::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("P-Asserted-Identity") => {
+                    Ok(Header::PAssertedIdentity(PAssertedIdentity::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("P-Preferred-Identity") => {
+                    Ok(Header::PPreferredIdentity(PPreferredIdentity::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Priority") => {
-                    Ok(Header::Priority(Priority::new(tokenizer.value)))
+                    Ok(Header::Priority(Priority::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Privacy") => Ok(Header::Privacy(Privacy::new(value))),
+                s if s.eq_ignore_ascii_case("Proxy-Authenticate") => {
+                    Ok(Header::ProxyAuthenticate(ProxyAuthenticate::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Proxy-Authorization") => {
+                    Ok(Header::ProxyAuthorization(ProxyAuthorization::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Proxy-Authenticate") => Ok(Header::ProxyAuthenticate(
-                    ProxyAuthenticate::new(tokenizer.value),
-                )),
-                s if s.eq_ignore_ascii_case("Proxy-Authorization") => Ok(
-                    Header::ProxyAuthorization(ProxyAuthorization::new(tokenizer.value)),
-                ),
                 s if s.eq_ignore_ascii_case("Proxy-Require") => {
-                    Ok(Header::ProxyRequire(ProxyRequire::new(tokenizer.value)))
+                    Ok(Header::ProxyRequire(ProxyRequire::new(value)))
                 }
+                s if s.eq_ignore_ascii_case("RAck") => Ok(Header::RAck(RAck::new(value))),
+                s if s.eq_ignore_ascii_case("RSeq") => Ok(Header::RSeq(RSeq::new(value))),
                 s if s.eq_ignore_ascii_case("Record-Route") => {
-                    Ok(Header::RecordRoute(RecordRoute::new(tokenizer.value)))
+                    Ok(Header::RecordRoute(RecordRoute::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Reply-To") => {
-                    Ok(Header::ReplyTo(ReplyTo::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Refer-To") || s.eq_ignore_ascii_case("r") => {
+                    Ok(Header::ReferTo(ReferTo::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Require") => {
-                    Ok(Header::Require(Require::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Referred-By") || s.eq_ignore_ascii_case("b") => {
+                    Ok(Header::ReferredBy(ReferredBy::new(value)))
                 }
+                s if s.eq_ignore_ascii_case("Replaces") => {
+                    Ok(Header::Replaces(Replaces::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("Reply-To") => Ok(Header::ReplyTo(ReplyTo::new(value))),
+                s if s.eq_ignore_ascii_case("Require") => Ok(Header::Require(Require::new(value))),
                 s if s.eq_ignore_ascii_case("Retry-After") => {
-                    Ok(Header::RetryAfter(RetryAfter::new(tokenizer.value)))
+                    Ok(Header::RetryAfter(RetryAfter::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Route") => {
-                    Ok(Header::Route(Route::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Route") => Ok(Header::Route(Route::new(value))),
+                s if s.eq_ignore_ascii_case("Server") => Ok(Header::Server(Server::new(value))),
+                s if s.eq_ignore_ascii_case("Session-Expires") || s.eq_ignore_ascii_case("x") => {
+                    Ok(Header::SessionExpires(SessionExpires::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Server") => {
-                    Ok(Header::Server(Server::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Subject") || s.eq_ignore_ascii_case("s") => {
+                    Ok(Header::Subject(Subject::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Subject") => {
-                    Ok(Header::Subject(Subject::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Supported") || s.eq_ignore_ascii_case("k") => {
+                    Ok(Header::Supported(Supported::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Supported") => {
-                    Ok(Header::Supported(Supported::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Target-Dialog") => {
+                    Ok(Header::TargetDialog(TargetDialog::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("Timestamp") => {
-                    Ok(Header::Timestamp(Timestamp::new(tokenizer.value)))
+                    Ok(Header::Timestamp(Timestamp::new(value)))
+                }
+                s if s.eq_ignore_ascii_case("To") || s.eq_ignore_ascii_case("t") => {
+                    Ok(Header::To(To::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("To") => Ok(Header::To(To::new(tokenizer.value))),
                 s if s.eq_ignore_ascii_case("Unsupported") => {
-                    Ok(Header::Unsupported(Unsupported::new(tokenizer.value)))
+                    Ok(Header::Unsupported(Unsupported::new(value)))
                 }
                 s if s.eq_ignore_ascii_case("User-Agent") => {
-                    Ok(Header::UserAgent(UserAgent::new(tokenizer.value)))
+                    Ok(Header::UserAgent(UserAgent::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("Via") => Ok(Header::Via(Via::new(tokenizer.value))),
-                s if s.eq_ignore_ascii_case("Warning") => {
-                    Ok(Header::Warning(Warning::new(tokenizer.value)))
+                s if s.eq_ignore_ascii_case("Via") || s.eq_ignore_ascii_case("v") => {
+                    Ok(Header::Via(Via::new(value)))
                 }
-                s if s.eq_ignore_ascii_case("WWW-Authenticate") => Ok(Header::WwwAuthenticate(
-                    WwwAuthenticate::new(tokenizer.value),
-                )),
-                _ => Ok(Header::Other(tokenizer.name.into(), tokenizer.value.into())),
+                s if s.eq_ignore_ascii_case("Warning") => Ok(Header::Warning(Warning::new(value))),
+                s if s.eq_ignore_ascii_case("WWW-Authenticate") => {
+                    Ok(Header::WwwAuthenticate(WwwAuthenticate::new(value)))
+                }
+                _ => Ok(Header::Other(tokenizer.name.into(), value.into())),
+            }
+        }
+    }
+
+    //replaces each folding (CRLF along with the surrounding whitespace) with a single SP, as
+    //RFC3261 section 7.3.1 describes
+    pub(crate) fn unfold(value: &str) -> Cow<'_, str> {
+        if !value.contains("\r\n") {
+            return Cow::Borrowed(value);
+        }
+
+        let is_wsp = |c: char| c == ' ' || c == '\t';
+        let mut unfolded = String::with_capacity(value.len());
+        for line in value.split("\r\n") {
+            if !unfolded.is_empty() {
+                unfolded.truncate(unfolded.trim_end_matches(is_wsp).len());
+                unfolded.push(' ');
             }
+            unfolded.push_str(line.trim_start_matches(is_wsp));
         }
+
+        Cow::Owned(unfolded)
     }
 
     #[derive(Debug, PartialEq, Eq, Utf8Tokenizer)]
@@ -269,24 +472,34 @@ pub mod tokenizer {
     }
 
     impl<'a> Tokenizer<'a> {
+        //folded lines (a CRLF followed by SP/HTAB) are kept as part of the value, unfolding
+        //takes place when converting to a Header. Whitespace is allowed before the colon too
+        //(HCOLON in RFC3261)
         pub fn tokenize(part: &'a [u8]) -> IResult<Self> {
             use crate::NomError;
             use nom::{
                 branch::alt,
                 bytes::complete::{tag, take_until, take_while1},
-                character::complete::space0,
-                combinator::{map, rest},
-                sequence::tuple,
+                character::complete::{space0, space1},
+                combinator::{opt, recognize, rest},
+                multi::many0,
+                sequence::{pair, tuple},
             };
 
-            let (rem, (name, _, _, value)) = tuple((
+            let (rem, (name, _, _, _, value, _)) = tuple((
                 take_while1(crate::parser_utils::is_token),
+                space0,
                 tag(":"),
                 space0,
-                alt((
-                    map(tuple((take_until("\r\n"), tag("\r\n"))), |(value, _)| value),
-                    rest,
+                recognize(pair(
+                    alt((take_until("\r\n"), rest)),
+                    many0(tuple((
+                        tag("\r\n"),
+                        space1,
+                        alt((take_until("\r\n"), rest)),
+                    ))),
                 )),
+                opt(tag("\r\n")),
             ))(part)
             .map_err(|_: NomError<'a>| TokenizerError::from(("header", part)).into())?;
 
@@ -0,0 +1,86 @@
+use crate::{
+    headers::{
+        header::{self, tokenizer::unfold},
+        typed::{Tokenize, TypedHeader},
+        Header,
+    },
+    Error,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// A borrowed view of a header, where both the name and the value point to the bytes the
+/// message was parsed from. It is what [RequestRef](crate::message::RequestRef),
+/// [ResponseRef](crate::message::ResponseRef) and
+/// [SipMessageRef](crate::message::SipMessageRef) hold instead of a [Header].
+///
+/// The value is kept exactly as found on the wire, hence folded lines (if any) are not unfolded
+/// and it might not be valid UTF-8 (check [Header::NonUtf8]), which is why [HeaderRef::value]
+/// can fail. Converting it to a [Header] (using `TryFrom`) gives you the unfolded, owned header.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HeaderRef<'a> {
+    pub name: &'a str,
+    pub value: &'a [u8],
+}
+
+impl<'a> HeaderRef<'a> {
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the value as found on the wire, failing if it is not valid UTF-8.
+    pub fn value(&self) -> Result<&'a str, Error> {
+        Ok(std::str::from_utf8(self.value)?)
+    }
+
+    /// Returns the value as found on the wire, as bytes.
+    pub fn value_bytes(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Checks if the header name matches the given name (ignoring case) or the given
+    /// compact form, if any.
+    pub fn is(&self, name: &str, compact: Option<&str>) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || compact
+                .map(|compact| self.name.eq_ignore_ascii_case(compact))
+                .unwrap_or(false)
+    }
+
+    /// Parses the value straight into a [typed](crate::headers::typed) header, without going
+    /// through an owned untyped header first. Folded lines are unfolded beforehand, which
+    /// allocates only when the value is actually folded.
+    pub fn typed<T>(&self) -> Result<T, Error>
+    where
+        T: for<'b> TypedHeader<'b>,
+    {
+        let value = unfold(self.value()?);
+        let tokenizer = <T as TypedHeader>::Tokenizer::tokenize(&value)?;
+
+        T::try_from(tokenizer)
+    }
+}
+
+impl<'a> TryFrom<header::Tokenizer<'a>> for HeaderRef<'a> {
+    type Error = Error;
+
+    fn try_from(tokenizer: header::Tokenizer<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: std::str::from_utf8(tokenizer.name)?,
+            value: tokenizer.value,
+        })
+    }
+}
+
+impl<'a> TryFrom<HeaderRef<'a>> for Header {
+    type Error = Error;
+
+    fn try_from(from: HeaderRef<'a>) -> Result<Self, Self::Error> {
+        header::Tokenizer::from((from.name.as_bytes(), from.value)).try_into()
+    }
+}
+
+impl<'a> std::fmt::Display for HeaderRef<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, String::from_utf8_lossy(self.value))
+    }
+}
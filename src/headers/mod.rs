@@ -1,14 +1,22 @@
 pub mod auth;
 pub mod header;
+pub mod header_ref;
+pub mod registry;
 pub mod typed;
 pub mod untyped;
 pub use header::Header;
+pub use header_ref::HeaderRef;
+pub use registry::{Custom, CustomHeader, HeaderRegistry};
 pub use untyped::*;
 
 /// Simple NewType around `Vec<Header>` that gives many helpful methods when dealing with headers
 /// in [super::Request], [super::Response] and [super::SipMessage].
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub struct Headers(Vec<Header>);
+///
+/// Headers of a parsed message also keep a copy of the bytes they were parsed from (along with
+/// the start line), so that the message can be written back byte for byte, apart from the
+/// parts that have been modified. That copy is not taken into account when comparing headers.
+#[derive(Clone, Default)]
+pub struct Headers(Vec<Header>, Option<crate::message::raw::Raw>);
 
 impl Headers {
     pub fn push(&mut self, h: Header) {
@@ -43,6 +51,139 @@ impl Headers {
     {
         self.0.retain(f)
     }
+
+    /// Iterates over the headers, yielding each value of a multi-value header (like
+    /// `Via: a, b`) as a separate header, check [Header::split].
+    pub fn values(&self) -> impl Iterator<Item = Header> + '_ {
+        self.iter().flat_map(|header| header.split())
+    }
+
+    /// Splits any multi-value header to one header per value, in place, so that each header
+    /// line holds a single value.
+    pub fn normalize(&mut self) {
+        self.0 = self.values().collect();
+    }
+
+    /// The opposite of [Headers::normalize]: all headers of the same multi-value kind are
+    /// combined (keeping their relative order) in a single header line, which takes the place
+    /// of the first one.
+    pub fn combine(&mut self) {
+        let mut combined: Vec<Header> = Vec::with_capacity(self.0.len());
+        for header in self.0.drain(..) {
+            let existing = combined.iter_mut().find(|existing| {
+                header.is_multi_value()
+                    && std::mem::discriminant(*existing) == std::mem::discriminant(&header)
+            });
+
+            match (existing, header.multi_value()) {
+                (Some(existing), Some(value)) => {
+                    let value =
+                        format!("{}, {}", existing.multi_value().unwrap_or_default(), value);
+                    *existing = existing.with_value(value);
+                }
+                _ => combined.push(header),
+            }
+        }
+
+        self.0 = combined;
+    }
+
+    //the start line and the headers of the message these headers were parsed from
+    pub(crate) fn raw(&self) -> Option<&crate::message::raw::Raw> {
+        self.1.as_ref()
+    }
+
+    //keeps the head these headers have just been parsed from, along with the span of each
+    //header in it, check raw::span
+    pub(crate) fn keep_raw(
+        &mut self,
+        head: &[u8],
+        start_line: &str,
+        spans: Vec<std::ops::Range<usize>>,
+    ) {
+        use crate::message::raw;
+
+        let headers = self
+            .0
+            .iter()
+            .map(raw::header_fingerprint)
+            .zip(spans)
+            .collect();
+        self.1 = Some(raw::Raw::new(head, start_line, headers));
+    }
+
+    //replaces each header with the outcome of f, along with the fingerprint of the original
+    //one kept in the raw copy, so that the replaced headers can still be written using their
+    //original bytes
+    pub(crate) fn try_map<F>(&mut self, f: F) -> Result<(), crate::Error>
+    where
+        F: Fn(&Header) -> Result<Header, crate::Error>,
+    {
+        use crate::message::raw::header_fingerprint;
+
+        let mut fingerprints = vec![];
+        for header in self.0.iter_mut() {
+            let mapped = f(header)?;
+            if self.1.is_some() {
+                fingerprints.push((header_fingerprint(header), header_fingerprint(&mapped)));
+            }
+            *header = mapped;
+        }
+        if let Some(raw) = self.1.as_mut() {
+            raw.remap(fingerprints);
+        }
+
+        Ok(())
+    }
+
+    //same as to_compact_string, but copies the values of non UTF-8 headers untouched
+    pub(crate) fn write_compact_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        for header in self.iter() {
+            match header {
+                Header::NonUtf8(name, value) => {
+                    write!(writer, "{}: ", name)?;
+                    writer.write_all(value)?;
+                }
+                header => writer.write_all(header.to_compact_string().as_bytes())?,
+            }
+            writer.write_all(b"\r\n")?;
+        }
+
+        Ok(())
+    }
+
+    /// Same as `to_string` but each header that has a compact form is written using its
+    /// compact name (check [Header::compact_name]).
+    pub fn to_compact_string(&self) -> String {
+        if self.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "{}\r\n",
+                self.iter()
+                    .map(|s| s.to_compact_string())
+                    .collect::<Vec<_>>()
+                    .join("\r\n")
+            )
+        }
+    }
+}
+
+impl PartialEq for Headers {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Headers {}
+
+impl std::fmt::Debug for Headers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Headers").field(&self.0).finish()
+    }
 }
 
 impl IntoIterator for Headers {
@@ -56,13 +197,13 @@ impl IntoIterator for Headers {
 
 impl std::convert::From<Header> for Headers {
     fn from(header: Header) -> Self {
-        Self(vec![header])
+        Self(vec![header], None)
     }
 }
 
 impl std::convert::From<Vec<Header>> for Headers {
     fn from(headers: Vec<Header>) -> Self {
-        Self(headers)
+        Self(headers, None)
     }
 }
 
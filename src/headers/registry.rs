@@ -0,0 +1,225 @@
+use crate::{message::HasHeaders, Error, Header, Headers, SipMessage};
+use std::{any::Any, convert::TryFrom, sync::Arc};
+
+/// Trait for application defined headers, that rsip does not know about. Such headers can be
+/// registered in a [HeaderRegistry] so that parsing gives you a [Header::Custom] holding the
+/// typed header, instead of a [Header::Other] holding just Strings.
+///
+/// The `CustomHeader` derive of `rsip-derives` implements this trait for any type that
+/// implements `FromStr` (with an error that can be converted to [Error]) and `Display`
+/// (writing just the value, without the header name):
+/// ```ignore
+/// #[derive(rsip_derives::CustomHeader)]
+/// #[header(display_name = "X-Tenant", compact_name = "xt")]
+/// struct XTenant(String);
+/// ```
+pub trait CustomHeader: Sized + Send + Sync + 'static {
+    const NAME: &'static str;
+    const COMPACT_NAME: Option<&'static str> = None;
+
+    fn parse(value: &str) -> Result<Self, Error>;
+    fn value(&self) -> String;
+}
+
+/// A typed [CustomHeader] in its type-erased form, as held by [Header::Custom].
+///
+/// The value is formatted once, when the header is created, and the typed header can be
+/// taken back using [Custom::typed]. Two custom headers are equal when their names (ignoring
+/// case) and values are equal.
+#[derive(Clone)]
+pub struct Custom {
+    name: &'static str,
+    compact_name: Option<&'static str>,
+    value: String,
+    typed: Arc<dyn Any + Send + Sync>,
+}
+
+impl Custom {
+    pub fn new<H: CustomHeader>(header: H) -> Self {
+        Self {
+            name: H::NAME,
+            compact_name: H::COMPACT_NAME,
+            value: header.value(),
+            typed: Arc::new(header),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn compact_name(&self) -> Option<&'static str> {
+        self.compact_name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the typed header, if this custom header holds an `H`.
+    pub fn typed<H: CustomHeader>(&self) -> Option<&H> {
+        self.typed.downcast_ref::<H>()
+    }
+}
+
+impl std::fmt::Debug for Custom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Custom")
+            .field("name", &self.name)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(other.name) && self.value == other.value
+    }
+}
+
+impl Eq for Custom {}
+
+impl std::fmt::Display for Custom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.value)
+    }
+}
+
+impl From<Custom> for Header {
+    fn from(custom: Custom) -> Self {
+        Header::Custom(custom)
+    }
+}
+
+/// A runtime registry of [CustomHeader]s, built by the application.
+///
+/// Messages parsed through the registry (using [HeaderRegistry::parse]) hold any registered
+/// header as a [Header::Custom], matching both its name and its compact name (ignoring case).
+/// Only headers that rsip does not know about (which would otherwise end up in [Header::Other])
+/// are looked up in the registry.
+/// ```
+/// use rsip::headers::{CustomHeader, HeaderRegistry};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct XTenant(String);
+///
+/// impl CustomHeader for XTenant {
+///     const NAME: &'static str = "X-Tenant";
+///
+///     fn parse(value: &str) -> Result<Self, rsip::Error> {
+///         Ok(Self(value.into()))
+///     }
+///
+///     fn value(&self) -> String {
+///         self.0.clone()
+///     }
+/// }
+///
+/// let registry = HeaderRegistry::new().with::<XTenant>();
+/// let header = registry.resolve_header(rsip::Header::Other("x-tenant".into(), "acme".into()));
+///
+/// match header {
+///     Ok(rsip::Header::Custom(custom)) => {
+///         assert_eq!(custom.typed::<XTenant>(), Some(&XTenant("acme".into())))
+///     }
+///     _ => panic!("not a custom header"),
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct HeaderRegistry {
+    entries: Vec<Entry>,
+}
+
+#[derive(Clone, Copy)]
+struct Entry {
+    name: &'static str,
+    compact_name: Option<&'static str>,
+    parse: fn(&str) -> Result<Custom, Error>,
+}
+
+impl Entry {
+    fn matches(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .compact_name
+                .map(|compact_name| compact_name.eq_ignore_ascii_case(name))
+                .unwrap_or(false)
+    }
+}
+
+impl HeaderRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `H`, replacing any header already registered under the same name.
+    pub fn register<H: CustomHeader>(&mut self) -> &mut Self {
+        self.entries.retain(|entry| !entry.matches(H::NAME));
+        self.entries.push(Entry {
+            name: H::NAME,
+            compact_name: H::COMPACT_NAME,
+            parse: |value| Ok(Custom::new(H::parse(value)?)),
+        });
+        self
+    }
+
+    pub fn with<H: CustomHeader>(mut self) -> Self {
+        self.register::<H>();
+        self
+    }
+
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.entries.iter().any(|entry| entry.matches(name))
+    }
+
+    /// Converts a [Header::Other] to a [Header::Custom], if its name has been registered. Any
+    /// other header is returned as is.
+    pub fn resolve_header(&self, header: Header) -> Result<Header, Error> {
+        match header {
+            Header::Other(name, value) => {
+                match self.entries.iter().find(|entry| entry.matches(&name)) {
+                    Some(entry) => Ok(Header::Custom((entry.parse)(&value)?)),
+                    None => Ok(Header::Other(name, value)),
+                }
+            }
+            header => Ok(header),
+        }
+    }
+
+    /// Resolves all the given headers, check [HeaderRegistry::resolve_header]. The bytes kept
+    /// from parsing are kept as well, so resolved headers are still written as they were parsed.
+    pub fn resolve_headers(&self, mut headers: Headers) -> Result<Headers, Error> {
+        headers.try_map(|header| self.resolve_header(header.clone()))?;
+
+        Ok(headers)
+    }
+
+    /// Resolves all the headers of the given message in place, check
+    /// [HeaderRegistry::resolve_header].
+    pub fn resolve<M: HasHeaders>(&self, message: &mut M) -> Result<(), Error> {
+        let headers = std::mem::take(message.headers_mut());
+        *message.headers_mut() = self.resolve_headers(headers)?;
+
+        Ok(())
+    }
+
+    /// Parses a [SipMessage] out of anything rsip can parse one from (like `&[u8]`, `&str`,
+    /// `String` or `Bytes`), resolving its headers using the registry.
+    pub fn parse<T>(&self, from: T) -> Result<SipMessage, Error>
+    where
+        SipMessage: TryFrom<T, Error = Error>,
+    {
+        let mut sip_message = SipMessage::try_from(from)?;
+        self.resolve(&mut sip_message)?;
+
+        Ok(sip_message)
+    }
+}
+
+impl std::fmt::Debug for HeaderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|entry| entry.name))
+            .finish()
+    }
+}
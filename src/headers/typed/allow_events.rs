@@ -0,0 +1,44 @@
+use crate::Error;
+use rsip_derives::TypedHeader;
+use std::convert::TryFrom;
+
+#[doc(hidden)]
+pub use super::tokenizers::TokenListTokenizer as Tokenizer;
+
+/// The `Allow-Events` header in its [typed](super) form. Each value is an event type, that is an
+/// event package followed by any templates (like `presence.winfo`).
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct AllowEvents(pub Vec<String>);
+
+impl AllowEvents {
+    /// Checks if the given event type is among the allowed ones, case-insensitively.
+    pub fn allows(&self, event_type: &str) -> bool {
+        self.0
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(event_type.trim()))
+    }
+}
+
+impl From<Vec<String>> for AllowEvents {
+    fn from(event_types: Vec<String>) -> Self {
+        Self(event_types)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for AllowEvents {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        if tokenizer.tokens.iter().any(|token| token.is_empty()) {
+            return Err(Error::ParseError("empty event type".into()));
+        }
+
+        Ok(Self(tokenizer.tokens.into_iter().map(Into::into).collect()))
+    }
+}
+
+impl std::fmt::Display for AllowEvents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(", "))
+    }
+}
@@ -0,0 +1,95 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueParamsTokenizer as Tokenizer;
+
+use crate::common::uri::Param;
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `Event` header in its [typed](super) form, as defined in
+/// [RFC6665](https://datatracker.ietf.org/doc/html/rfc6665#section-8.2.1).
+///
+/// An event type like `presence.winfo` is split to its package (`presence`) and its templates
+/// (`winfo`). The `id` param, used to tell apart subscriptions of the same dialog, has its own
+/// field, any other param is kept in `params`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct Event {
+    pub package: String,
+    pub templates: Vec<String>,
+    pub id: Option<String>,
+    pub params: Vec<Param>,
+}
+
+impl Event {
+    pub fn new(package: impl Into<String>) -> Self {
+        Self {
+            package: package.into(),
+            templates: vec![],
+            id: None,
+            params: vec![],
+        }
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The full event type, that is the package followed by any templates (like
+    /// `presence.winfo`).
+    pub fn event_type(&self) -> String {
+        std::iter::once(&self.package)
+            .chain(self.templates.iter())
+            .map(|part| part.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Checks if the given event type (package along with any templates) is the one of this
+    /// header. Event types are compared case-insensitively.
+    pub fn is(&self, event_type: &str) -> bool {
+        self.event_type().eq_ignore_ascii_case(event_type.trim())
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for Event {
+    type Error = crate::Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        let mut parts = tokenizer.value.split('.');
+        let package = match parts.next() {
+            Some(package) if !package.is_empty() => package.to_string(),
+            _ => return Err(crate::Error::ParseError("missing event package".into())),
+        };
+        let templates = parts.map(Into::into).collect::<Vec<String>>();
+
+        let mut id = None;
+        let mut params = vec![];
+        for param in tokenizer.params {
+            match (param.name, param.value) {
+                (name, Some(value)) if name.eq_ignore_ascii_case("id") => id = Some(value.into()),
+                _ => params.push(param.try_into()?),
+            }
+        }
+
+        Ok(Self {
+            package,
+            templates,
+            id,
+            params,
+        })
+    }
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.event_type())?;
+        if let Some(id) = &self.id {
+            write!(f, ";id={}", id)?;
+        }
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
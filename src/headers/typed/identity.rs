@@ -0,0 +1,53 @@
+use super::tokenizers::DisplayUriParamsTokenizer as Tokenizer;
+
+use crate::common::{uri::Param, Uri};
+use std::convert::{TryFrom, TryInto};
+
+/// A single identity (a name-addr or addr-spec, like `"Alice" <sip:alice@atlanta.com>`), as
+/// found in the `P-Asserted-Identity` and `P-Preferred-Identity` headers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Identity {
+    pub display_name: Option<String>,
+    pub uri: Uri,
+    pub params: Vec<Param>,
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for Identity {
+    type Error = crate::Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            display_name: tokenizer.display_name.map(Into::into),
+            uri: tokenizer.uri.try_into()?,
+            params: tokenizer
+                .params
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl std::fmt::Display for Identity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(display_name) = &self.display_name {
+            write!(f, "{} ", display_name)?;
+        }
+        write!(f, "<{}>", self.uri)?;
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Uri> for Identity {
+    fn from(uri: Uri) -> Self {
+        Self {
+            display_name: None,
+            uri,
+            params: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueParamsTokenizer as Tokenizer;
+
+use crate::common::uri::Param;
+use crate::Error;
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `Min-SE` header in its [typed](super) form, as defined in
+/// [RFC4028](https://datatracker.ietf.org/doc/html/rfc4028#section-5). It holds the minimum
+/// session interval in seconds, any param is kept in `params`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct MinSe {
+    pub seconds: u32,
+    pub params: Vec<Param>,
+}
+
+impl MinSe {
+    pub fn new(seconds: u32) -> Self {
+        Self {
+            seconds,
+            params: vec![],
+        }
+    }
+}
+
+impl From<u32> for MinSe {
+    fn from(seconds: u32) -> Self {
+        Self::new(seconds)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for MinSe {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            seconds: tokenizer.value.parse::<u32>()?,
+            params: tokenizer
+                .params
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl std::fmt::Display for MinSe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.seconds)?;
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
@@ -6,13 +6,16 @@
 //! [push notifications](https://datatracker.ietf.org/doc/html/rfc8599) etc
 //!
 
-//This is not a header but a common helper
+//These are not headers but common helpers
+pub mod identity;
 pub mod media_type;
+pub use identity::Identity;
 pub use media_type::MediaType;
 
 pub mod accept;
 pub mod alert_info;
 pub mod allow;
+pub mod allow_events;
 pub mod authentication_info;
 pub mod authorization;
 pub mod call_info;
@@ -21,14 +24,27 @@ pub mod content_disposition;
 pub mod content_type;
 pub mod cseq;
 pub mod error_info;
+pub mod event;
 pub mod from;
 pub mod in_reply_to;
+pub mod min_se;
+pub mod p_asserted_identity;
+pub mod p_preferred_identity;
 pub mod priority;
+pub mod privacy;
 pub mod proxy_authenticate;
 pub mod proxy_authorization;
+pub mod rack;
 pub mod record_route;
+pub mod refer_to;
+pub mod referred_by;
+pub mod replaces;
 pub mod reply_to;
 pub mod route;
+pub mod rseq;
+pub mod session_expires;
+pub mod subscription_state;
+pub mod target_dialog;
 pub mod to;
 pub mod tokenizers;
 pub mod via;
@@ -38,6 +54,7 @@ pub mod www_authenticate;
 pub use accept::Accept;
 pub use alert_info::AlertInfo;
 pub use allow::Allow;
+pub use allow_events::AllowEvents;
 pub use authentication_info::AuthenticationInfo;
 pub use authorization::Authorization;
 pub use call_info::CallInfo;
@@ -46,14 +63,27 @@ pub use content_disposition::ContentDisposition;
 pub use content_type::ContentType;
 pub use cseq::CSeq;
 pub use error_info::ErrorInfo;
+pub use event::Event;
 pub use from::From;
 pub use in_reply_to::InReplyTo;
+pub use min_se::MinSe;
+pub use p_asserted_identity::PAssertedIdentity;
+pub use p_preferred_identity::PPreferredIdentity;
 pub use priority::Priority;
+pub use privacy::Privacy;
 pub use proxy_authenticate::ProxyAuthenticate;
 pub use proxy_authorization::ProxyAuthorization;
+pub use rack::RAck;
 pub use record_route::RecordRoute;
+pub use refer_to::ReferTo;
+pub use referred_by::ReferredBy;
+pub use replaces::Replaces;
 pub use reply_to::ReplyTo;
 pub use route::Route;
+pub use rseq::RSeq;
+pub use session_expires::SessionExpires;
+pub use subscription_state::SubscriptionState;
+pub use target_dialog::TargetDialog;
 pub use to::To;
 pub use via::Via;
 pub use warning::Warning;
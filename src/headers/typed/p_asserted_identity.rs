@@ -0,0 +1,52 @@
+#[doc(hidden)]
+pub use super::tokenizers::DisplayUriParamsListTokenizer as Tokenizer;
+
+use super::Identity;
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `P-Asserted-Identity` header in its [typed](super) form, as defined in
+/// [RFC3325](https://datatracker.ietf.org/doc/html/rfc3325#section-9.1). It holds one or more
+/// identities, usually a SIP and/or a tel URI.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct PAssertedIdentity(pub Vec<Identity>);
+
+impl PAssertedIdentity {
+    pub fn identities(&self) -> &[Identity] {
+        &self.0
+    }
+}
+
+impl From<Vec<Identity>> for PAssertedIdentity {
+    fn from(identities: Vec<Identity>) -> Self {
+        Self(identities)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for PAssertedIdentity {
+    type Error = crate::Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        Ok(Self(
+            tokenizer
+                .0
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+}
+
+impl std::fmt::Display for PAssertedIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|identity| identity.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
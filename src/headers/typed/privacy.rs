@@ -0,0 +1,102 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueTokenizer as Tokenizer;
+
+use crate::Error;
+use rsip_derives::TypedHeader;
+use std::convert::TryFrom;
+
+/// The `Privacy` header in its [typed](super) form, as defined in
+/// [RFC3323](https://datatracker.ietf.org/doc/html/rfc3323#section-4.2) and
+/// [RFC3325](https://datatracker.ietf.org/doc/html/rfc3325#section-9.3). It holds the set of
+/// privacy types requested, separated by `;` on the wire.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct Privacy(pub Vec<PrivacyValue>);
+
+impl Privacy {
+    /// Checks if the given privacy type has been requested.
+    pub fn contains(&self, value: &PrivacyValue) -> bool {
+        self.0.contains(value)
+    }
+
+    /// Checks if any privacy has been requested, that is, there is a privacy type other than
+    /// `none`, which explicitly asks for no privacy at all.
+    pub fn is_requested(&self) -> bool {
+        !self.contains(&PrivacyValue::None) && !self.0.is_empty()
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum PrivacyValue {
+    Header,
+    Session,
+    User,
+    None,
+    Critical,
+    Id,
+    Other(String),
+}
+
+impl From<Vec<PrivacyValue>> for Privacy {
+    fn from(values: Vec<PrivacyValue>) -> Self {
+        Self(values)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for Privacy {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        let values = tokenizer
+            .value
+            .split(';')
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        if values.iter().any(|value| value.is_empty()) {
+            return Err(Error::tokenizer(("privacy header", tokenizer.value)));
+        }
+
+        Ok(Self(values.into_iter().map(Into::into).collect()))
+    }
+}
+
+impl<'a> From<&'a str> for PrivacyValue {
+    fn from(value: &'a str) -> Self {
+        match value {
+            s if s.eq_ignore_ascii_case("header") => Self::Header,
+            s if s.eq_ignore_ascii_case("session") => Self::Session,
+            s if s.eq_ignore_ascii_case("user") => Self::User,
+            s if s.eq_ignore_ascii_case("none") => Self::None,
+            s if s.eq_ignore_ascii_case("critical") => Self::Critical,
+            s if s.eq_ignore_ascii_case("id") => Self::Id,
+            s => Self::Other(s.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for PrivacyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Header => write!(f, "header"),
+            Self::Session => write!(f, "session"),
+            Self::User => write!(f, "user"),
+            Self::None => write!(f, "none"),
+            Self::Critical => write!(f, "critical"),
+            Self::Id => write!(f, "id"),
+            Self::Other(other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl std::fmt::Display for Privacy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(";")
+        )
+    }
+}
@@ -0,0 +1,48 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueTokenizer as Tokenizer;
+
+use crate::{common::Method, Error};
+use rsip_derives::TypedHeader;
+use std::convert::TryFrom;
+
+/// The `RAck` header in its [typed](super) form, as defined in
+/// [RFC3262](https://datatracker.ietf.org/doc/html/rfc3262#section-7.2). It is found in a PRACK
+/// and acknowledges a reliable provisional response, using its `RSeq` number along with the
+/// sequence number and method of its `CSeq`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct RAck {
+    pub rseq: u32,
+    pub cseq: u32,
+    pub method: Method,
+}
+
+impl From<(u32, u32, Method)> for RAck {
+    fn from(tuple: (u32, u32, Method)) -> Self {
+        Self {
+            rseq: tuple.0,
+            cseq: tuple.1,
+            method: tuple.2,
+        }
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for RAck {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        match tokenizer.value.split_whitespace().collect::<Vec<_>>()[..] {
+            [rseq, cseq, method] => Ok(Self {
+                rseq: rseq.parse::<u32>()?,
+                cseq: cseq.parse::<u32>()?,
+                method: method.parse::<Method>()?,
+            }),
+            _ => Err(Error::tokenizer(("rack header", tokenizer.value))),
+        }
+    }
+}
+
+impl std::fmt::Display for RAck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.rseq, self.cseq, self.method)
+    }
+}
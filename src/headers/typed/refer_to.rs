@@ -0,0 +1,72 @@
+#[doc(hidden)]
+pub use super::tokenizers::DisplayUriParamsTokenizer as Tokenizer;
+
+use crate::common::{uri::Param, Uri};
+use crate::headers::{typed::Replaces, untyped::ToTypedHeader, UntypedHeader};
+use crate::Error;
+use rsip_derives::{TypedHeader, UriAndParamsHelpers};
+use std::convert::{TryFrom, TryInto};
+
+/// The `Refer-To` header in its [typed](super) form, as defined in
+/// [RFC3515](https://datatracker.ietf.org/doc/html/rfc3515#section-2.1). The URI may carry
+/// headers (like `Replaces`) that the referee should add in the request it sends, check
+/// [Uri::header_fields].
+#[derive(TypedHeader, UriAndParamsHelpers, Eq, PartialEq, Clone, Debug)]
+pub struct ReferTo {
+    pub display_name: Option<String>,
+    pub uri: Uri,
+    pub params: Vec<Param>,
+}
+
+impl ReferTo {
+    /// Returns the `Replaces` header embedded in the URI, if any, which is how an attended
+    /// transfer is requested.
+    pub fn replaces(&self) -> Result<Option<Replaces>, Error> {
+        self.uri
+            .headers
+            .iter()
+            .find(|header| header.name().eq_ignore_ascii_case("Replaces"))
+            .map(|header| crate::headers::Replaces::new(header.value()).into_typed())
+            .transpose()
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for ReferTo {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            display_name: tokenizer.display_name.map(Into::into),
+            uri: tokenizer.uri.try_into()?,
+            params: tokenizer
+                .params
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl std::fmt::Display for ReferTo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(display_name) = &self.display_name {
+            write!(f, "{} ", display_name)?;
+        }
+        write!(f, "<{}>", self.uri)?;
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::convert::From<Uri> for ReferTo {
+    fn from(uri: Uri) -> Self {
+        Self {
+            display_name: None,
+            uri,
+            params: Default::default(),
+        }
+    }
+}
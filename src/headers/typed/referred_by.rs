@@ -0,0 +1,57 @@
+#[doc(hidden)]
+pub use super::tokenizers::DisplayUriParamsTokenizer as Tokenizer;
+
+use crate::common::{uri::Param, Uri};
+use crate::Error;
+use rsip_derives::{TypedHeader, UriAndParamsHelpers};
+use std::convert::{TryFrom, TryInto};
+
+/// The `Referred-By` header in its [typed](super) form, as defined in
+/// [RFC3892](https://datatracker.ietf.org/doc/html/rfc3892#section-3). The `cid` param, pointing
+/// to the body part that holds the Referred-By token, is kept in `params`.
+#[derive(TypedHeader, UriAndParamsHelpers, Eq, PartialEq, Clone, Debug)]
+pub struct ReferredBy {
+    pub display_name: Option<String>,
+    pub uri: Uri,
+    pub params: Vec<Param>,
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for ReferredBy {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            display_name: tokenizer.display_name.map(Into::into),
+            uri: tokenizer.uri.try_into()?,
+            params: tokenizer
+                .params
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl std::fmt::Display for ReferredBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(display_name) = &self.display_name {
+            write!(f, "{} ", display_name)?;
+        }
+        write!(f, "<{}>", self.uri)?;
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::convert::From<Uri> for ReferredBy {
+    fn from(uri: Uri) -> Self {
+        Self {
+            display_name: None,
+            uri,
+            params: Default::default(),
+        }
+    }
+}
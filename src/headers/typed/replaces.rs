@@ -0,0 +1,91 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueParamsTokenizer as Tokenizer;
+
+use crate::common::uri::{param::Tag, Param};
+use crate::Error;
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `Replaces` header in its [typed](super) form, as defined in
+/// [RFC3891](https://datatracker.ietf.org/doc/html/rfc3891#section-6.1). It identifies the
+/// dialog to be replaced by its Call-ID along with the `to-tag` and `from-tag` params, which are
+/// both mandatory. Any other param is kept in `params`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct Replaces {
+    pub call_id: String,
+    pub to_tag: Tag,
+    pub from_tag: Tag,
+    pub early_only: bool,
+    pub params: Vec<Param>,
+}
+
+impl Replaces {
+    pub fn new(call_id: impl Into<String>, to_tag: Tag, from_tag: Tag) -> Self {
+        Self {
+            call_id: call_id.into(),
+            to_tag,
+            from_tag,
+            early_only: false,
+            params: vec![],
+        }
+    }
+
+    pub fn with_early_only(mut self) -> Self {
+        self.early_only = true;
+        self
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for Replaces {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        if tokenizer.value.is_empty() {
+            return Err(Error::ParseError("Replaces: missing Call-ID".into()));
+        }
+
+        let mut to_tag = None;
+        let mut from_tag = None;
+        let mut early_only = false;
+        let mut params = vec![];
+        for param in tokenizer.params {
+            match (param.name, param.value) {
+                (name, Some(value)) if name.eq_ignore_ascii_case("to-tag") => {
+                    to_tag = Some(Tag::new(value))
+                }
+                (name, Some(value)) if name.eq_ignore_ascii_case("from-tag") => {
+                    from_tag = Some(Tag::new(value))
+                }
+                (name, None) if name.eq_ignore_ascii_case("early-only") => early_only = true,
+                _ => params.push(param.try_into()?),
+            }
+        }
+
+        Ok(Self {
+            call_id: tokenizer.value.into(),
+            to_tag: to_tag.ok_or_else(|| Error::ParseError("Replaces: missing to-tag".into()))?,
+            from_tag: from_tag
+                .ok_or_else(|| Error::ParseError("Replaces: missing from-tag".into()))?,
+            early_only,
+            params,
+        })
+    }
+}
+
+impl std::fmt::Display for Replaces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{};to-tag={};from-tag={}",
+            self.call_id, self.to_tag, self.from_tag
+        )?;
+        if self.early_only {
+            write!(f, ";early-only")?;
+        }
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueTokenizer as Tokenizer;
+
+use rsip_derives::TypedHeader;
+use std::convert::TryFrom;
+
+/// The `RSeq` header in its [typed](super) form, as defined in
+/// [RFC3262](https://datatracker.ietf.org/doc/html/rfc3262#section-7.1). It holds the sequence
+/// number of a reliable provisional response.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct RSeq(pub u32);
+
+impl From<u32> for RSeq {
+    fn from(seq: u32) -> Self {
+        Self(seq)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for RSeq {
+    type Error = crate::Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        Ok(Self(tokenizer.value.trim().parse::<u32>()?))
+    }
+}
+
+impl std::fmt::Display for RSeq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
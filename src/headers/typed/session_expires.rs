@@ -0,0 +1,100 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueParamsTokenizer as Tokenizer;
+
+use crate::common::uri::Param;
+use crate::Error;
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `Session-Expires` header in its [typed](super) form, as defined in
+/// [RFC4028](https://datatracker.ietf.org/doc/html/rfc4028#section-4). It holds the session
+/// interval in seconds and, optionally, which side of the dialog refreshes the session. Any other
+/// param is kept in `params`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct SessionExpires {
+    pub seconds: u32,
+    pub refresher: Option<Refresher>,
+    pub params: Vec<Param>,
+}
+
+impl SessionExpires {
+    pub fn new(seconds: u32) -> Self {
+        Self {
+            seconds,
+            refresher: None,
+            params: vec![],
+        }
+    }
+
+    pub fn with_refresher(mut self, refresher: Refresher) -> Self {
+        self.refresher = Some(refresher);
+        self
+    }
+}
+
+/// The side of the dialog that is responsible for refreshing the session, found in the
+/// `refresher` param of the `Session-Expires` header.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Refresher {
+    Uac,
+    Uas,
+}
+
+impl From<u32> for SessionExpires {
+    fn from(seconds: u32) -> Self {
+        Self::new(seconds)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for SessionExpires {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        let mut session_expires = Self::new(tokenizer.value.parse::<u32>()?);
+        for param in tokenizer.params {
+            match (param.name, param.value) {
+                (name, Some(value)) if name.eq_ignore_ascii_case("refresher") => {
+                    session_expires.refresher = Some(value.parse()?)
+                }
+                _ => session_expires.params.push(param.try_into()?),
+            }
+        }
+
+        Ok(session_expires)
+    }
+}
+
+impl std::str::FromStr for Refresher {
+    type Err = Error;
+
+    fn from_str(refresher: &str) -> Result<Self, Self::Err> {
+        match refresher {
+            s if s.eq_ignore_ascii_case("uac") => Ok(Self::Uac),
+            s if s.eq_ignore_ascii_case("uas") => Ok(Self::Uas),
+            s => Err(Error::ParseError(format!("invalid refresher: {}", s))),
+        }
+    }
+}
+
+impl std::fmt::Display for Refresher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uac => write!(f, "uac"),
+            Self::Uas => write!(f, "uas"),
+        }
+    }
+}
+
+impl std::fmt::Display for SessionExpires {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.seconds)?;
+        if let Some(refresher) = &self.refresher {
+            write!(f, ";refresher={}", refresher)?;
+        }
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
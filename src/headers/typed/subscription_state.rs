@@ -0,0 +1,151 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueParamsTokenizer as Tokenizer;
+
+use crate::{common::uri::Param, Error};
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `Subscription-State` header in its [typed](super) form, as defined in
+/// [RFC6665](https://datatracker.ietf.org/doc/html/rfc6665#section-8.2.3).
+///
+/// The `reason`, `expires` and `retry-after` params have their own fields, any other param is
+/// kept in `params`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct SubscriptionState {
+    pub state: State,
+    pub reason: Option<Reason>,
+    pub expires: Option<u32>,
+    pub retry_after: Option<u32>,
+    pub params: Vec<Param>,
+}
+
+impl SubscriptionState {
+    pub fn new(state: State) -> Self {
+        Self {
+            state,
+            reason: None,
+            expires: None,
+            retry_after: None,
+            params: vec![],
+        }
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        matches!(self.state, State::Terminated)
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum State {
+    Active,
+    Pending,
+    Terminated,
+    Other(String),
+}
+
+/// The reason a subscription was terminated, along with what the subscriber is expected to do,
+/// check [RFC6665](https://datatracker.ietf.org/doc/html/rfc6665#section-4.1.3).
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Reason {
+    Deactivated,
+    Probation,
+    Rejected,
+    Timeout,
+    Giveup,
+    Noresource,
+    Invariant,
+    Other(String),
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for SubscriptionState {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        let state = match tokenizer.value {
+            s if s.eq_ignore_ascii_case("active") => State::Active,
+            s if s.eq_ignore_ascii_case("pending") => State::Pending,
+            s if s.eq_ignore_ascii_case("terminated") => State::Terminated,
+            "" => return Err(Error::ParseError("missing subscription state".into())),
+            s => State::Other(s.into()),
+        };
+
+        let mut subscription_state = Self::new(state);
+        for param in tokenizer.params {
+            match (param.name, param.value) {
+                (name, Some(value)) if name.eq_ignore_ascii_case("reason") => {
+                    subscription_state.reason = Some(value.into())
+                }
+                (name, Some(value)) if name.eq_ignore_ascii_case("expires") => {
+                    subscription_state.expires = Some(value.parse::<u32>()?)
+                }
+                (name, Some(value)) if name.eq_ignore_ascii_case("retry-after") => {
+                    subscription_state.retry_after = Some(value.parse::<u32>()?)
+                }
+                _ => subscription_state.params.push(param.try_into()?),
+            }
+        }
+
+        Ok(subscription_state)
+    }
+}
+
+impl<'a> From<&'a str> for Reason {
+    fn from(reason: &'a str) -> Self {
+        match reason {
+            s if s.eq_ignore_ascii_case("deactivated") => Self::Deactivated,
+            s if s.eq_ignore_ascii_case("probation") => Self::Probation,
+            s if s.eq_ignore_ascii_case("rejected") => Self::Rejected,
+            s if s.eq_ignore_ascii_case("timeout") => Self::Timeout,
+            s if s.eq_ignore_ascii_case("giveup") => Self::Giveup,
+            s if s.eq_ignore_ascii_case("noresource") => Self::Noresource,
+            s if s.eq_ignore_ascii_case("invariant") => Self::Invariant,
+            s => Self::Other(s.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::Pending => write!(f, "pending"),
+            Self::Terminated => write!(f, "terminated"),
+            Self::Other(other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl std::fmt::Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deactivated => write!(f, "deactivated"),
+            Self::Probation => write!(f, "probation"),
+            Self::Rejected => write!(f, "rejected"),
+            Self::Timeout => write!(f, "timeout"),
+            Self::Giveup => write!(f, "giveup"),
+            Self::Noresource => write!(f, "noresource"),
+            Self::Invariant => write!(f, "invariant"),
+            Self::Other(other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.state)?;
+        if let Some(reason) = &self.reason {
+            write!(f, ";reason={}", reason)?;
+        }
+        if let Some(expires) = self.expires {
+            write!(f, ";expires={}", expires)?;
+        }
+        if let Some(retry_after) = self.retry_after {
+            write!(f, ";retry-after={}", retry_after)?;
+        }
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
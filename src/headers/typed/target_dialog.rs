@@ -0,0 +1,79 @@
+#[doc(hidden)]
+pub use super::tokenizers::ValueParamsTokenizer as Tokenizer;
+
+use crate::common::uri::{param::Tag, Param};
+use crate::Error;
+use rsip_derives::TypedHeader;
+use std::convert::{TryFrom, TryInto};
+
+/// The `Target-Dialog` header in its [typed](super) form, as defined in
+/// [RFC4538](https://datatracker.ietf.org/doc/html/rfc4538#section-7). It identifies a dialog
+/// by its Call-ID along with the `local-tag` and `remote-tag` params, which are both mandatory.
+/// Any other param is kept in `params`.
+#[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+pub struct TargetDialog {
+    pub call_id: String,
+    pub local_tag: Tag,
+    pub remote_tag: Tag,
+    pub params: Vec<Param>,
+}
+
+impl TargetDialog {
+    pub fn new(call_id: impl Into<String>, local_tag: Tag, remote_tag: Tag) -> Self {
+        Self {
+            call_id: call_id.into(),
+            local_tag,
+            remote_tag,
+            params: vec![],
+        }
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for TargetDialog {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
+        if tokenizer.value.is_empty() {
+            return Err(Error::ParseError("Target-Dialog: missing Call-ID".into()));
+        }
+
+        let mut local_tag = None;
+        let mut remote_tag = None;
+        let mut params = vec![];
+        for param in tokenizer.params {
+            match (param.name, param.value) {
+                (name, Some(value)) if name.eq_ignore_ascii_case("local-tag") => {
+                    local_tag = Some(Tag::new(value))
+                }
+                (name, Some(value)) if name.eq_ignore_ascii_case("remote-tag") => {
+                    remote_tag = Some(Tag::new(value))
+                }
+                _ => params.push(param.try_into()?),
+            }
+        }
+
+        Ok(Self {
+            call_id: tokenizer.value.into(),
+            local_tag: local_tag
+                .ok_or_else(|| Error::ParseError("Target-Dialog: missing local-tag".into()))?,
+            remote_tag: remote_tag
+                .ok_or_else(|| Error::ParseError("Target-Dialog: missing remote-tag".into()))?,
+            params,
+        })
+    }
+}
+
+impl std::fmt::Display for TargetDialog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{};local-tag={};remote-tag={}",
+            self.call_id, self.local_tag, self.remote_tag
+        )?;
+        for param in self.params.iter() {
+            write!(f, "{}", param)?;
+        }
+
+        Ok(())
+    }
+}
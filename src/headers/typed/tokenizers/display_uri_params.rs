@@ -19,14 +19,12 @@ impl<'a> Tokenize<'a> for DisplayUriParamsTokenizer<'a> {
         };
 
         if part.contains('<') {
-            let (_, (display_name, _, uri, _, params)) = tuple::<_, _, VerboseError<&str>, _>((
-                take_until("<"),
-                tag("<"),
-                take_until(">"),
-                tag(">"),
-                rest,
-            ))(part)
-            .map_err(|_| Error::tokenizer(("header parts", part)))?;
+            let (rem, display_name) = display_name(part)?;
+            let (_, (_, uri, _, params)) =
+                tuple::<_, _, VerboseError<&str>, _>((tag("<"), take_until(">"), tag(">"), rest))(
+                    rem,
+                )
+                .map_err(|_| Error::tokenizer(("header parts", part)))?;
 
             let (rem, params) = many0(uri::param::Tokenizer::tokenize)(params)
                 .map_err(|_| Error::tokenizer(("params", part)))?;
@@ -40,10 +38,11 @@ impl<'a> Tokenize<'a> for DisplayUriParamsTokenizer<'a> {
                 params,
             })
         } else {
-            let (_, (uri, params)) = tuple((
+            let (rem, (uri, params)) = tuple((
                 uri::Tokenizer::tokenize_without_params,
                 many0(uri::param::Tokenizer::tokenize),
             ))(part)?;
+            is_empty_or_fail_with(rem, ("addr-spec left trailing input", part))?;
 
             Ok(Self {
                 display_name: None,
@@ -53,3 +52,45 @@ impl<'a> Tokenize<'a> for DisplayUriParamsTokenizer<'a> {
         }
     }
 }
+
+//the display name is either a quoted string (where `\\` escapes the next char) or anything
+//found before the `<`, which should be a list of tokens separated by whitespace (RFC3261
+//section 25.1), but is accepted as is, as real-world traffic often breaks that rule. Strict
+//parsing checks it, using is_token_display_name
+fn display_name(part: &str) -> Result<(&str, &str), Error> {
+    let trimmed = part.trim_start();
+
+    if let Some(quoted) = trimmed.strip_prefix('"') {
+        let mut escaped = false;
+        let end = quoted
+            .char_indices()
+            .find_map(|(index, c)| match (escaped, c) {
+                (true, _) => {
+                    escaped = false;
+                    None
+                }
+                (false, '\\') => {
+                    escaped = true;
+                    None
+                }
+                (false, '"') => Some(index),
+                _ => None,
+            });
+
+        return match end {
+            Some(end) => Ok((trimmed[end + 2..].trim_start(), &trimmed[..end + 2])),
+            None => Err(Error::tokenizer(("display name", part))),
+        };
+    }
+
+    let index = part.find('<').unwrap_or(part.len());
+    Ok((&part[index..], &part[..index]))
+}
+
+//checks that an unquoted display name is a list of tokens separated by whitespace
+pub(crate) fn is_token_display_name(display_name: &str) -> bool {
+    display_name.starts_with('"')
+        || display_name
+            .bytes()
+            .all(|c| crate::parser_utils::is_token(c) || c == b' ' || c == b'\t')
+}
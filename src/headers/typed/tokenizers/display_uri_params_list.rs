@@ -0,0 +1,24 @@
+use crate::{
+    headers::typed::{tokenizers::DisplayUriParamsTokenizer, Tokenize},
+    Error,
+};
+
+//a comma-separated list of name-addr/addr-spec values, like the one of `P-Asserted-Identity`
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct DisplayUriParamsListTokenizer<'a>(pub Vec<DisplayUriParamsTokenizer<'a>>);
+
+impl<'a> Tokenize<'a> for DisplayUriParamsListTokenizer<'a> {
+    fn tokenize(part: &'a str) -> Result<Self, Error> {
+        let values = crate::utils::split_values(part);
+        if values.is_empty() {
+            return Err(Error::tokenizer(("empty name-addr list", part)));
+        }
+
+        Ok(Self(
+            values
+                .into_iter()
+                .map(DisplayUriParamsTokenizer::tokenize)
+                .collect::<Result<Vec<_>, Error>>()?,
+        ))
+    }
+}
@@ -1,6 +1,7 @@
 mod auth;
 mod cseq;
 mod display_uri_params;
+mod display_uri_params_list;
 mod name_params;
 mod name_params_list;
 mod name_value;
@@ -8,12 +9,15 @@ mod token_list;
 mod uri_with_params;
 mod uri_with_params_list;
 mod value;
+mod value_params;
 mod via;
 mod warning;
 
 pub use auth::AuthTokenizer;
 pub use cseq::CseqTokenizer;
+pub(crate) use display_uri_params::is_token_display_name;
 pub use display_uri_params::DisplayUriParamsTokenizer;
+pub use display_uri_params_list::DisplayUriParamsListTokenizer;
 pub use name_params::NameParamsTokenizer;
 pub use name_params_list::NameParamsListTokenizer;
 pub use name_value::NameValueTokenizer;
@@ -21,5 +25,6 @@ pub use token_list::TokenListTokenizer;
 pub use uri_with_params::UriWithParamsTokenizer;
 pub use uri_with_params_list::UriWithParamsListTokenizer;
 pub use value::ValueTokenizer;
+pub use value_params::ValueParamsTokenizer;
 pub use via::ViaTokenizer;
 pub use warning::WarningTokenizer;
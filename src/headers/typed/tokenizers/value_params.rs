@@ -0,0 +1,27 @@
+use crate::{common::uri, headers::typed::Tokenize, Error};
+
+//a plain value (like a Call-ID) followed by header params, which may have no value at all
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ValueParamsTokenizer<'a> {
+    pub value: &'a str,
+    pub params: Vec<uri::param::Tokenizer<'a, &'a str, char>>,
+}
+
+impl<'a> Tokenize<'a> for ValueParamsTokenizer<'a> {
+    fn tokenize(part: &'a str) -> Result<Self, Error> {
+        use crate::parser_utils::is_empty_or_fail_with;
+        use crate::NomStrError;
+        use nom::{bytes::complete::take_till, multi::many0};
+
+        let (rem, value) = take_till(|c| c == ';')(part)
+            .map_err(|_: NomStrError<'a>| Error::tokenizer(("value", part)))?;
+        let (rem, params) = many0(uri::param::Tokenizer::tokenize)(rem)
+            .map_err(|_| Error::tokenizer(("value params", part)))?;
+        is_empty_or_fail_with(rem, ("value params tokenizing left trailing input", part))?;
+
+        Ok(Self {
+            value: value.trim(),
+            params,
+        })
+    }
+}
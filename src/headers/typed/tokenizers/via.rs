@@ -19,18 +19,34 @@ pub struct ViaTokenizer<'a> {
 impl<'a> Tokenize<'a> for ViaTokenizer<'a> {
     fn tokenize(part: &'a str) -> Result<Self, Error> {
         use nom::{
-            bytes::complete::tag, character::complete::space1, multi::many0, sequence::tuple,
+            bytes::complete::{tag, take_while1},
+            character::complete::{digit1, space0, space1},
+            multi::many0,
+            sequence::tuple,
         };
 
-        let (_, (version, _, transport, _, uri, params)) = tuple((
-            version::Tokenizer::tokenize,
-            tag("/"),
-            transport::Tokenizer::tokenize,
-            space1,
-            uri::Tokenizer::tokenize_without_params,
-            many0(param::Tokenizer::tokenize),
-        ))(part)
-        .map_err(|_| Error::tokenizer(("via (typed) header", part)))?;
+        //the protocol parts can be separated by whitespace (SLASH in RFC3261)
+        let (_, (_, _, _, _, major, _, minor, _, _, _, transport, _, uri, params)) =
+            tuple((
+                tag("SIP"),
+                space0,
+                tag("/"),
+                space0,
+                digit1,
+                tag("."),
+                digit1,
+                space0,
+                tag("/"),
+                space0,
+                take_while1(|c: char| c.is_ascii() && crate::parser_utils::is_token(c as u8)),
+                space1,
+                uri::Tokenizer::tokenize_without_params,
+                many0(param::Tokenizer::tokenize),
+            ))(part)
+            .map_err(|_| Error::tokenizer(("via (typed) header", part)))?;
+
+        let version = version::Tokenizer::from((major, minor));
+        let transport = transport::Tokenizer::from(transport);
 
         Ok(Self {
             version,
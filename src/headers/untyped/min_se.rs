@@ -0,0 +1,6 @@
+use rsip_derives::{ToTypedHeader, UntypedHeader};
+
+/// The `Min-SE` header in its [untyped](super) form.
+#[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[header(display_name = "Min-SE")]
+pub struct MinSe(String);
@@ -16,6 +16,7 @@ pub mod accept_encoding;
 pub mod accept_language;
 pub mod alert_info;
 pub mod allow;
+pub mod allow_events;
 pub mod authentication_info;
 pub mod authorization;
 pub mod call_id;
@@ -36,20 +37,31 @@ pub mod in_reply_to;
 pub mod max_forwards;
 pub mod mime_version;
 pub mod min_expires;
+pub mod min_se;
 pub mod organization;
+pub mod p_asserted_identity;
+pub mod p_preferred_identity;
 pub mod priority;
+pub mod privacy;
 pub mod proxy_authenticate;
 pub mod proxy_authorization;
 pub mod proxy_require;
+pub mod rack;
 pub mod record_route;
+pub mod refer_to;
+pub mod referred_by;
+pub mod replaces;
 pub mod reply_to;
 pub mod require;
 pub mod retry_after;
 pub mod route;
+pub mod rseq;
 pub mod server;
+pub mod session_expires;
 pub mod subject;
 pub mod subscription_state;
 pub mod supported;
+pub mod target_dialog;
 pub mod timestamp;
 pub mod to;
 pub mod unsupported;
@@ -63,6 +75,7 @@ pub use accept_encoding::AcceptEncoding;
 pub use accept_language::AcceptLanguage;
 pub use alert_info::AlertInfo;
 pub use allow::Allow;
+pub use allow_events::AllowEvents;
 pub use authentication_info::AuthenticationInfo;
 pub use authorization::Authorization;
 pub use call_id::CallId;
@@ -83,20 +96,31 @@ pub use in_reply_to::InReplyTo;
 pub use max_forwards::MaxForwards;
 pub use mime_version::MimeVersion;
 pub use min_expires::MinExpires;
+pub use min_se::MinSe;
 pub use organization::Organization;
+pub use p_asserted_identity::PAssertedIdentity;
+pub use p_preferred_identity::PPreferredIdentity;
 pub use priority::Priority;
+pub use privacy::Privacy;
 pub use proxy_authenticate::ProxyAuthenticate;
 pub use proxy_authorization::ProxyAuthorization;
 pub use proxy_require::ProxyRequire;
+pub use rack::RAck;
 pub use record_route::RecordRoute;
+pub use refer_to::ReferTo;
+pub use referred_by::ReferredBy;
+pub use replaces::Replaces;
 pub use reply_to::ReplyTo;
 pub use require::Require;
 pub use retry_after::RetryAfter;
 pub use route::Route;
+pub use rseq::RSeq;
 pub use server::Server;
+pub use session_expires::SessionExpires;
 pub use subject::Subject;
 pub use subscription_state::SubscriptionState;
 pub use supported::Supported;
+pub use target_dialog::TargetDialog;
 pub use timestamp::Timestamp;
 pub use to::To;
 pub use unsupported::Unsupported;
@@ -0,0 +1,5 @@
+use rsip_derives::{ToTypedHeader, UntypedHeader};
+
+/// The `P-Asserted-Identity` header in its [untyped](super) form.
+#[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+pub struct PAssertedIdentity(String);
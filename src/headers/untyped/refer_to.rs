@@ -0,0 +1,5 @@
+use rsip_derives::{ToTypedHeader, UntypedHeader};
+
+/// The `Refer-To` header in its [untyped](super) form.
+#[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+pub struct ReferTo(String);
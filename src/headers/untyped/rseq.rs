@@ -0,0 +1,6 @@
+use rsip_derives::{ToTypedHeader, UntypedHeader};
+
+/// The `RSeq` header in its [untyped](super) form.
+#[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[header(display_name = "RSeq")]
+pub struct RSeq(String);
@@ -1,5 +1,5 @@
-use rsip_derives::UntypedHeader;
+use rsip_derives::{ToTypedHeader, UntypedHeader};
 
-/// The `Subscription` header in its [untyped](super) form.
-#[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+/// The `Subscription-State` header in its [untyped](super) form.
+#[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
 pub struct SubscriptionState(String);
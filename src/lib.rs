@@ -82,10 +82,7 @@
 //!     display_name: Some("Bob".into()),
 //!     uri: rsip::Uri {
 //!         scheme: Some(rsip::Scheme::Sips),
-//!         auth: Some(rsip::Auth {
-//!             user: "Bob".into(),
-//!             password: None,
-//!         }),
+//!         auth: Some(rsip::Auth::from(("Bob", Option::<String>::None))),
 //!         host_with_port: rsip::Domain::from("biloxi.example.com").into(),
 //!         ..Default::default()
 //!     },
@@ -121,10 +118,7 @@
 //! #     display_name: Some("Bob".into()),
 //! #     uri: rsip::Uri {
 //! #         scheme: Some(rsip::Scheme::Sips),
-//! #         auth: Some(rsip::Auth {
-//! #             user: "Bob".into(),
-//! #             password: None,
-//! #         }),
+//! #         auth: Some(rsip::Auth::from(("Bob", Option::<String>::None))),
 //! #         host_with_port: rsip::Domain::from("biloxi.example.com").into(),
 //! #         ..Default::default()
 //! #     },
@@ -144,10 +138,7 @@
 //! #     display_name: Some("Bob".into()),
 //! #     uri: rsip::Uri {
 //! #         scheme: Some(rsip::Scheme::Sips),
-//! #         auth: Some(rsip::Auth {
-//! #             user: "Bob".into(),
-//! #             password: None,
-//! #         }),
+//! #         auth: Some(rsip::Auth::from(("Bob", Option::<String>::None))),
 //! #         host_with_port: rsip::Domain::from("biloxi.example.com").into(),
 //! #         ..Default::default()
 //! #     },
@@ -265,7 +256,7 @@ pub mod headers;
 pub mod message;
 pub mod services;
 
-pub use error::{Error, TokenizerError};
+pub use error::{Error, ErrorKind, ErrorLocation, TokenizeDetails, TokenizerError};
 
 pub use headers::{Header, Headers};
 pub use message::{Request, Response, SipMessage};
@@ -282,7 +273,7 @@ pub mod typed {
 pub mod prelude {
     pub use crate::{
         headers::{typed::TypedHeader, ToTypedHeader, UntypedHeader},
-        message::{HasHeaders, HeadersExt},
+        message::{HasHeaderRefs, HasHeaders, HeadersExt, HeadersRefExt},
     };
 }
 
@@ -369,6 +360,86 @@ pub(crate) mod utils {
             false => Some(input),
         }
     }
+
+    //decodes any `%HH` escapes, failing on malformed escapes or if the decoded bytes are not
+    //valid UTF-8
+    pub fn percent_decode(input: &str) -> Result<String, crate::Error> {
+        if !input.contains('%') {
+            return Ok(input.into());
+        }
+
+        let bytes = input.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'%' {
+                let byte = input
+                    .get(index + 1..index + 3)
+                    .filter(|hex| hex.bytes().all(|c| c.is_ascii_hexdigit()))
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| {
+                        crate::Error::ParseError(format!("invalid percent-encoding: {}", input))
+                    })?;
+                decoded.push(byte);
+                index += 3;
+            } else {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+
+        String::from_utf8(decoded).map_err(|e| crate::Error::Utf8Error(e.to_string()))
+    }
+
+    //same as percent_decode, but returns the input as is when it can't be decoded
+    pub fn percent_decode_lossy(input: &str) -> String {
+        percent_decode(input).unwrap_or_else(|_| input.into())
+    }
+
+    //escapes (`%HH`) any byte that does not fall in the allowed characters
+    pub fn percent_encode<F>(input: &str, is_allowed: F) -> String
+    where
+        F: Fn(char) -> bool,
+    {
+        let mut encoded = String::with_capacity(input.len());
+        for c in input.chars() {
+            if is_allowed(c) {
+                encoded.push(c);
+            } else {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    encoded.push_str(&format!("%{:02X}", byte));
+                }
+            }
+        }
+
+        encoded
+    }
+
+    //splits a header value on the commas that separate its values (RFC3261 section 7.3.1),
+    //skipping any commas inside quoted strings or angle brackets, trimming each value and
+    //dropping empty ones
+    pub fn split_values(input: &str) -> Vec<&str> {
+        let mut values = vec![];
+        let (mut start, mut in_quotes, mut in_brackets, mut escaped) = (0, false, false, false);
+        for (index, c) in input.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if in_quotes => escaped = true,
+                '"' => in_quotes = !in_quotes,
+                '<' if !in_quotes => in_brackets = true,
+                '>' if !in_quotes => in_brackets = false,
+                ',' if !in_quotes && !in_brackets => {
+                    values.push(&input[start..index]);
+                    start = index + 1;
+                }
+                _ => (),
+            }
+        }
+        values.push(&input[start..]);
+
+        values.into_iter().filter_map(opt_trim).collect()
+    }
 }
 
 pub(crate) mod parser_utils {
@@ -124,6 +124,31 @@ pub trait HeadersExt: super::HasHeaders {
         all_headers!(self.headers().iter(), Header::Contact)
     }
 
+    /// Returns each `Via` value separately, even when multiple values have been combined in a
+    /// single header line, check [Headers::values](crate::Headers::values).
+    fn via_values(&self) -> Vec<headers::Via> {
+        all_headers!(self.headers().values(), Header::Via)
+    }
+
+    /// Same as [HeadersExt::via_values] but for `Contact` headers.
+    fn contact_values(&self) -> Vec<headers::Contact> {
+        all_headers!(self.headers().values(), Header::Contact)
+    }
+
+    /// Same as [HeadersExt::via_values] but for `Record-Route` headers.
+    fn record_route_values(&self) -> Vec<headers::RecordRoute> {
+        all_headers!(self.headers().values(), Header::RecordRoute)
+    }
+
+    /// Same as [HeadersExt::via_values] but for `Route` headers.
+    fn route_values(&self) -> Vec<headers::Route> {
+        all_headers!(self.headers().values(), Header::Route)
+    }
+
+    fn content_length_header(&self) -> Option<&headers::ContentLength> {
+        header_opt!(self.headers().iter(), Header::ContentLength)
+    }
+
     fn record_route_header(&self) -> Option<&headers::RecordRoute> {
         header_opt!(self.headers().iter(), Header::RecordRoute)
     }
@@ -152,6 +177,64 @@ pub trait HeadersExt: super::HasHeaders {
         header_opt!(self.headers().iter(), Header::MinExpires)
     }
 
+    fn session_expires_header(&self) -> Option<&headers::SessionExpires> {
+        header_opt!(self.headers().iter(), Header::SessionExpires)
+    }
+
+    fn min_se_header(&self) -> Option<&headers::MinSe> {
+        header_opt!(self.headers().iter(), Header::MinSe)
+    }
+
+    fn rseq_header(&self) -> Option<&headers::RSeq> {
+        header_opt!(self.headers().iter(), Header::RSeq)
+    }
+
+    fn rack_header(&self) -> Option<&headers::RAck> {
+        header_opt!(self.headers().iter(), Header::RAck)
+    }
+
+    fn p_asserted_identity_header(&self) -> Option<&headers::PAssertedIdentity> {
+        header_opt!(self.headers().iter(), Header::PAssertedIdentity)
+    }
+
+    fn p_preferred_identity_header(&self) -> Option<&headers::PPreferredIdentity> {
+        header_opt!(self.headers().iter(), Header::PPreferredIdentity)
+    }
+
+    fn privacy_header(&self) -> Option<&headers::Privacy> {
+        header_opt!(self.headers().iter(), Header::Privacy)
+    }
+
+    fn refer_to_header(&self) -> Option<&headers::ReferTo> {
+        header_opt!(self.headers().iter(), Header::ReferTo)
+    }
+
+    fn referred_by_header(&self) -> Option<&headers::ReferredBy> {
+        header_opt!(self.headers().iter(), Header::ReferredBy)
+    }
+
+    fn replaces_header(&self) -> Option<&headers::Replaces> {
+        header_opt!(self.headers().iter(), Header::Replaces)
+    }
+
+    fn target_dialog_header(&self) -> Option<&headers::TargetDialog> {
+        header_opt!(self.headers().iter(), Header::TargetDialog)
+    }
+
+    /// Returns the first [custom](crate::headers::CustomHeader) header of type `H`, if any.
+    /// Custom headers are only there when the message has been parsed (or resolved) through a
+    /// [HeaderRegistry](crate::headers::HeaderRegistry) where `H` was registered.
+    fn custom_header<H>(&self) -> Option<&H>
+    where
+        H: headers::CustomHeader,
+        Self: Sized,
+    {
+        self.headers().iter().find_map(|header| match header {
+            Header::Custom(custom) => custom.typed::<H>(),
+            _ => None,
+        })
+    }
+
     //TODO: this is not correct? this is just the branch id
     //however RFC3261 states clear ways on how transactions should be matched
     fn transaction_id(&self) -> Result<Branch, Error> {
@@ -0,0 +1,143 @@
+use crate::{headers::HeaderRef, Error};
+
+/// The [HeadersExt](crate::message::HeadersExt) counterpart for borrowed messages
+/// ([RequestRef](crate::message::RequestRef), [ResponseRef](crate::message::ResponseRef) and
+/// [SipMessageRef](crate::message::SipMessageRef)).
+///
+/// Instead of an untyped header, each method returns the matching [HeaderRef], which points to
+/// the parsed bytes. Headers are matched by name ignoring case, taking into account compact
+/// forms too.
+pub trait HeadersRefExt<'a>: super::HasHeaderRefs<'a> {
+    /// Returns the first header with the given name (ignoring case), if any.
+    fn header(&self, name: &str) -> Option<HeaderRef<'a>> {
+        self.headers()
+            .iter()
+            .find(|header| header.is(name, None))
+            .copied()
+    }
+
+    fn to_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "To", Some("t")).ok_or_else(|| Error::missing_header("To"))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "From", Some("f")).ok_or_else(|| Error::missing_header("From"))
+    }
+
+    fn via_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "Via", Some("v")).ok_or_else(|| Error::missing_header("Via"))
+    }
+
+    fn call_id_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "Call-ID", Some("i")).ok_or_else(|| Error::missing_header("CallID"))
+    }
+
+    fn cseq_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "CSeq", None).ok_or_else(|| Error::missing_header("CSeq"))
+    }
+
+    fn max_forwards_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "Max-Forwards", None)
+            .ok_or_else(|| Error::missing_header("Max-Forwards"))
+    }
+
+    fn contact_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), "Contact", Some("m")).ok_or_else(|| Error::missing_header("Contact"))
+    }
+
+    fn contact_headers(&self) -> Vec<HeaderRef<'a>> {
+        self.headers()
+            .iter()
+            .filter(|header| header.is("Contact", Some("m")))
+            .copied()
+            .collect()
+    }
+
+    fn content_length_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Content-Length", Some("l"))
+    }
+
+    fn record_route_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Record-Route", None)
+    }
+
+    fn route_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Route", None)
+    }
+
+    fn user_agent_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "User-Agent", None)
+    }
+
+    fn authorization_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Authorization", None)
+    }
+
+    fn www_authenticate_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "WWW-Authenticate", None)
+    }
+
+    fn expires_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Expires", None)
+    }
+
+    fn min_expires_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Min-Expires", None)
+    }
+
+    fn session_expires_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Session-Expires", Some("x"))
+    }
+
+    fn min_se_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Min-SE", None)
+    }
+
+    fn rseq_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "RSeq", None)
+    }
+
+    fn rack_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "RAck", None)
+    }
+
+    fn p_asserted_identity_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "P-Asserted-Identity", None)
+    }
+
+    fn p_preferred_identity_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "P-Preferred-Identity", None)
+    }
+
+    fn privacy_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Privacy", None)
+    }
+
+    fn refer_to_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Refer-To", Some("r"))
+    }
+
+    fn referred_by_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Referred-By", Some("b"))
+    }
+
+    fn replaces_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Replaces", None)
+    }
+
+    fn target_dialog_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), "Target-Dialog", None)
+    }
+}
+
+fn find<'a>(headers: &[HeaderRef<'a>], name: &str, compact: Option<&str>) -> Option<HeaderRef<'a>> {
+    headers
+        .iter()
+        .find(|header| header.is(name, compact))
+        .copied()
+}
+
+impl<'a> HeadersRefExt<'a> for crate::message::RequestRef<'a> {}
+impl<'a> HeadersRefExt<'a> for crate::message::ResponseRef<'a> {}
+impl<'a> HeadersRefExt<'a> for crate::message::SipMessageRef<'a> {}
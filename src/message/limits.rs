@@ -0,0 +1,180 @@
+//! Limits on the size of a message, to protect against hostile input.
+
+use crate::{common::StatusCode, Error};
+
+/// The resource limits that [SipMessage::parse_with](crate::SipMessage::parse_with) enforces,
+/// through [ParseOptions](super::ParseOptions). The input is checked against them before being
+/// tokenized, so a message exceeding any of them is rejected without allocating anything for it.
+/// The `TryFrom<&[u8]>` conversions of messages (and those relying on them) enforce the default
+/// limits, while [stream::decode](super::stream::decode) takes the limits to enforce.
+///
+/// * `max_message_size` is the size of the whole message in bytes.
+/// * `max_headers` is the number of header lines (folded lines count as part of their header).
+/// * `max_line_length` is the length in bytes of any line of the start line or the headers.
+/// * `max_params_per_header` is the number of `;` separated params of a header, counted
+///   outside quoted strings (so URI params are included).
+/// * `max_uri_length` is the length in bytes of the Request-URI.
+/// * `max_body_size` is the size of the body in bytes.
+///
+/// The defaults are generous enough for any legitimate message, use [Limits::unlimited] to
+/// disable the checks altogether.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Limits {
+    pub max_message_size: usize,
+    pub max_headers: usize,
+    pub max_line_length: usize,
+    pub max_params_per_header: usize,
+    pub max_uri_length: usize,
+    pub max_body_size: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_message_size: 1024 * 1024,
+            max_headers: 256,
+            max_line_length: 16 * 1024,
+            max_params_per_header: 64,
+            max_uri_length: 8 * 1024,
+            max_body_size: 1024 * 1024,
+        }
+    }
+}
+
+/// The limit that a message exceeded, held by [Error::LimitExceeded] along with the maximum
+/// allowed value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Limit {
+    MessageSize,
+    Headers,
+    LineLength,
+    ParamsPerHeader,
+    UriLength,
+    BodySize,
+}
+
+impl Limit {
+    /// The status code a server should respond with, when a request exceeds this limit.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::BodySize => StatusCode::RequestEntityTooLarge,
+            Self::UriLength => StatusCode::RequestUriTooLong,
+            _ => StatusCode::MessageTooLarge,
+        }
+    }
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MessageSize => write!(f, "message size"),
+            Self::Headers => write!(f, "headers"),
+            Self::LineLength => write!(f, "line length"),
+            Self::ParamsPerHeader => write!(f, "params per header"),
+            Self::UriLength => write!(f, "uri length"),
+            Self::BodySize => write!(f, "body size"),
+        }
+    }
+}
+
+impl Limits {
+    pub fn unlimited() -> Self {
+        Self {
+            max_message_size: usize::MAX,
+            max_headers: usize::MAX,
+            max_line_length: usize::MAX,
+            max_params_per_header: usize::MAX,
+            max_uri_length: usize::MAX,
+            max_body_size: usize::MAX,
+        }
+    }
+
+    /// Checks the given message against the limits, without tokenizing it. Both CRLF and bare
+    /// LF line endings are recognized.
+    pub fn check(&self, part: &[u8]) -> Result<(), Error> {
+        exceeds(Limit::MessageSize, part.len(), self.max_message_size)?;
+
+        let mut headers = 0;
+        let mut params = 0;
+        let mut rem = part;
+        let mut first_line = true;
+
+        loop {
+            let (line, next) = match rem.iter().position(|c| *c == b'\n') {
+                Some(index) => (&rem[..index], Some(&rem[index + 1..])),
+                None => (rem, None),
+            };
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            exceeds(Limit::LineLength, line.len(), self.max_line_length)?;
+
+            if line.is_empty() {
+                exceeds(
+                    Limit::BodySize,
+                    next.map(|body| body.len()).unwrap_or(0),
+                    self.max_body_size,
+                )?;
+                break;
+            }
+
+            match (first_line, line[0]) {
+                (true, _) => {
+                    exceeds(Limit::UriLength, uri_len(line), self.max_uri_length)?;
+                    first_line = false;
+                }
+                (false, b' ') | (false, b'\t') => params += params_len(line),
+                (false, _) => {
+                    headers += 1;
+                    exceeds(Limit::Headers, headers, self.max_headers)?;
+                    params = params_len(line);
+                }
+            }
+            exceeds(Limit::ParamsPerHeader, params, self.max_params_per_header)?;
+
+            match next {
+                Some(next) => rem = next,
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn exceeds(limit: Limit, value: usize, max: usize) -> Result<(), Error> {
+    match value > max {
+        true => Err(Error::LimitExceeded(limit, max)),
+        false => Ok(()),
+    }
+}
+
+//the Request-URI is the second element of a request line, responses have none
+fn uri_len(start_line: &[u8]) -> usize {
+    if start_line.len() >= 4 && start_line[..4].eq_ignore_ascii_case(b"SIP/") {
+        return 0;
+    }
+
+    start_line
+        .split(|c| *c == b' ')
+        .filter(|element| !element.is_empty())
+        .nth(1)
+        .map(|uri| uri.len())
+        .unwrap_or(0)
+}
+
+fn params_len(line: &[u8]) -> usize {
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut params = 0;
+
+    for c in line {
+        match (quoted, escaped, c) {
+            (true, true, _) => escaped = false,
+            (true, false, b'\\') => escaped = true,
+            (_, _, b'"') => quoted = !quoted,
+            (false, _, b';') => params += 1,
+            _ => (),
+        }
+    }
+
+    params
+}
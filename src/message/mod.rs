@@ -1,14 +1,29 @@
 #[macro_use]
 pub mod header_macros;
 pub mod headers_ext;
+pub mod headers_ref_ext;
+pub mod limits;
+pub mod parse_options;
+pub(crate) mod raw;
 pub mod request;
+pub mod request_ref;
 pub mod response;
+pub mod response_ref;
 pub mod sip_message;
+pub mod sip_message_ref;
+pub mod stream;
 
 pub use headers_ext::HeadersExt;
+pub use headers_ref_ext::HeadersRefExt;
+pub use limits::{Limit, Limits};
+pub use parse_options::{ParseMode, ParseOptions, ParseWarning};
 pub use request::Request;
+pub use request_ref::RequestRef;
 pub use response::Response;
+pub use response_ref::ResponseRef;
 pub use sip_message::SipMessage;
+pub use sip_message_ref::SipMessageRef;
+pub use stream::{Decoded, Decoder};
 
 /// Simple trait to signify that the underlying type has headers so that the
 /// [HeadersExt](crate::message::HeadersExt) trait
@@ -17,3 +32,10 @@ pub trait HasHeaders {
     fn headers(&self) -> &crate::headers::Headers;
     fn headers_mut(&mut self) -> &mut crate::headers::Headers;
 }
+
+/// Same as [HasHeaders] but for the borrowed messages, like [SipMessageRef], so that the
+/// [HeadersRefExt](crate::message::HeadersRefExt) trait can implement all the header methods
+/// by default.
+pub trait HasHeaderRefs<'a> {
+    fn headers(&self) -> &[crate::headers::HeaderRef<'a>];
+}
@@ -0,0 +1,325 @@
+//! Options for parsing a [SipMessage] out of bytes, check [SipMessage::parse_with].
+
+use crate::{
+    message::{HasHeaders, HeadersExt, Limits},
+    Error, Header, SipMessage,
+};
+
+/// How strictly a message should be checked while parsing.
+///
+/// * `Standard` is what the `TryFrom` conversions do: the message needs to be tokenized
+///   successfully, but its headers are not checked against each other.
+/// * `Strict` additionally checks the message against
+///   [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261), useful for conformance testing:
+///   the mandatory headers must be present and well formed (`To`, `From`, `Via`, `Contact` and
+///   `CSeq` are checked as typed headers, while `Max-Forwards` can be missing from an RFC2543
+///   request, whose top `Via` has no `z9hG4bK` branch) and the single-valued ones can't be
+///   repeated, the `CSeq` method of a request must match the request method, the Request-URI
+///   can't have headers and a `Date` must be in GMT. If the body is longer than the `Content-Length`, the
+///   extra octets are discarded (as a stream would do), while a shorter body is an error.
+/// * `Lenient` accepts sloppy input, that would otherwise fail to parse: bare LF line endings,
+///   trailing whitespace, a missing final CRLF and a non-uppercase `SIP/2.0`. Each of them is
+///   recorded as a [ParseWarning].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum ParseMode {
+    #[default]
+    Standard,
+    Strict,
+    Lenient,
+}
+
+/// The options of [SipMessage::parse_with]: the [ParseMode] and the resource [Limits], which
+/// are enforced in every mode.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions {
+    pub mode: ParseMode,
+    pub limits: Limits,
+}
+
+impl ParseOptions {
+    pub fn strict() -> Self {
+        Self {
+            mode: ParseMode::Strict,
+            ..Default::default()
+        }
+    }
+
+    pub fn lenient() -> Self {
+        Self {
+            mode: ParseMode::Lenient,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+/// A deviation from the SIP grammar that the lenient mode accepted. Lines are 1-based and refer
+/// to the input given to the parser.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseWarning {
+    BareLineFeed { line: usize },
+    TrailingWhitespace { line: usize },
+    MissingFinalCrlf,
+    LowercaseVersion,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BareLineFeed { line } => write!(f, "line {} ends with a bare LF", line),
+            Self::TrailingWhitespace { line } => {
+                write!(f, "line {} has trailing whitespace", line)
+            }
+            Self::MissingFinalCrlf => write!(f, "headers are not terminated by an empty line"),
+            Self::LowercaseVersion => write!(f, "SIP version is not uppercase"),
+        }
+    }
+}
+
+impl SipMessage {
+    /// Parses a [SipMessage] using the given [ParseOptions]. Along with the message, it returns
+    /// the deviations that were accepted, which can only be found in [ParseMode::Lenient].
+    pub fn parse_with(
+        part: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), Error> {
+        options.limits.check(part)?;
+
+        match options.mode {
+            ParseMode::Standard => Ok((Self::parse(part)?, vec![])),
+            ParseMode::Strict => {
+                let mut sip_message = Self::parse(part)?;
+                check_strict(&mut sip_message)?;
+
+                Ok((sip_message, vec![]))
+            }
+            ParseMode::Lenient => {
+                let (normalized, warnings) = normalize(part);
+                match warnings.is_empty() {
+                    true => Ok((Self::parse(part)?, warnings)),
+                    false => Ok((Self::parse(&normalized)?, warnings)),
+                }
+            }
+        }
+    }
+}
+
+fn check_strict(sip_message: &mut SipMessage) -> Result<(), Error> {
+    use crate::headers::{
+        typed::{tokenizers::ViaTokenizer, Tokenize},
+        ToTypedHeader, UntypedHeader,
+    };
+
+    sip_message.to_header()?.typed()?;
+    sip_message.from_header()?.typed()?;
+    check_display_name(sip_message.to_header()?.value())?;
+    check_display_name(sip_message.from_header()?.value())?;
+    sip_message.call_id_header()?;
+    let cseq_method = sip_message.cseq_header()?.typed()?.method;
+    //extension transports are allowed, so Via values are only checked to be well formed
+    sip_message.via_header()?;
+    let mut rfc2543 = false;
+    for (index, via) in sip_message.via_values().iter().enumerate() {
+        let via = ViaTokenizer::tokenize(via.value())?;
+        //a top Via without the RFC3261 branch cookie comes from an RFC2543 element
+        if index == 0 {
+            rfc2543 = !via.params.iter().any(|param| {
+                param.name.eq_ignore_ascii_case("branch")
+                    && matches!(param.value, Some(value) if value.starts_with("z9hG4bK"))
+            });
+        }
+    }
+    for contact in sip_message.contact_values() {
+        if contact.value() != "*" {
+            contact.typed()?;
+            check_display_name(contact.value())?;
+        }
+    }
+    check_duplicates(sip_message)?;
+
+    if let SipMessage::Request(request) = sip_message {
+        //Max-Forwards was optional in RFC2543, such requests should still be accepted (RFC4475
+        //section 3.4.1)
+        match request.max_forwards_header() {
+            Err(_) if rfc2543 => (),
+            max_forwards => {
+                max_forwards?.num()?;
+            }
+        }
+        if cseq_method != request.method {
+            return Err(Error::ParseError(format!(
+                "CSeq method {} does not match request method {}",
+                cseq_method, request.method
+            )));
+        }
+        if !request.uri.headers.is_empty() {
+            return Err(Error::ParseError("Request-URI has headers".into()));
+        }
+    }
+
+    let date = sip_message
+        .headers()
+        .iter()
+        .find_map(|header| match header {
+            Header::Date(date) => Some(date.value()),
+            _ => None,
+        });
+    if let Some(date) = date {
+        if !date.trim_end().ends_with("GMT") {
+            return Err(Error::ParseError(format!("Date {} is not in GMT", date)));
+        }
+    }
+
+    if let Some(content_length) = sip_message.content_length_header() {
+        let length = content_length.length()? as usize;
+        if length > sip_message.body().len() {
+            return Err(Error::ParseError(format!(
+                "Content-Length {} does not match body length {}",
+                length,
+                sip_message.body().len()
+            )));
+        }
+        //any octets past the body are discarded (RFC3261 section 18.3)
+        sip_message.body_mut().truncate(length);
+    }
+
+    Ok(())
+}
+
+//unquoted display names are accepted as is when parsing, while RFC3261 only allows tokens
+fn check_display_name(value: &str) -> Result<(), Error> {
+    use crate::headers::typed::{
+        tokenizers::{is_token_display_name, DisplayUriParamsTokenizer},
+        Tokenize,
+    };
+
+    match DisplayUriParamsTokenizer::tokenize(value)?.display_name {
+        Some(display_name) if !is_token_display_name(display_name) => {
+            Err(Error::tokenizer(("display name", value)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_duplicates(sip_message: &SipMessage) -> Result<(), Error> {
+    let mut seen = std::collections::HashSet::new();
+
+    for header in sip_message.headers().iter() {
+        let single = matches!(
+            header,
+            Header::To(_)
+                | Header::From(_)
+                | Header::CallId(_)
+                | Header::CSeq(_)
+                | Header::MaxForwards(_)
+                | Header::ContentLength(_)
+                | Header::ContentType(_)
+        );
+
+        if single && !seen.insert(std::mem::discriminant(header)) {
+            let header = header.to_string();
+            let name = header.split(':').next().unwrap_or_default();
+            return Err(Error::ParseError(format!("{} header is repeated", name)));
+        }
+    }
+
+    Ok(())
+}
+
+//rewrites the start line and the headers so that they follow the grammar, the body is kept as is
+fn normalize(part: &[u8]) -> (Vec<u8>, Vec<ParseWarning>) {
+    let mut normalized = Vec::with_capacity(part.len() + 2);
+    let mut warnings = vec![];
+    let mut rem = part;
+    let mut line_number = 0;
+
+    loop {
+        line_number += 1;
+        let (mut line, next) = match rem.iter().position(|c| *c == b'\n') {
+            Some(index) => (&rem[..index], Some(&rem[index + 1..])),
+            None => (rem, None),
+        };
+
+        match line.strip_suffix(b"\r") {
+            Some(stripped) => line = stripped,
+            None if next.is_some() => {
+                warnings.push(ParseWarning::BareLineFeed { line: line_number })
+            }
+            None => (),
+        }
+
+        if line.is_empty() {
+            match next {
+                Some(body) => {
+                    normalized.extend_from_slice(b"\r\n");
+                    normalized.extend_from_slice(body);
+                }
+                None => {
+                    warnings.push(ParseWarning::MissingFinalCrlf);
+                    normalized.extend_from_slice(b"\r\n");
+                }
+            }
+            break;
+        }
+
+        let trimmed = trim_end(line);
+        if trimmed.len() != line.len() {
+            warnings.push(ParseWarning::TrailingWhitespace { line: line_number });
+        }
+
+        match line_number {
+            1 => normalized.extend_from_slice(&normalize_start_line(trimmed, &mut warnings)),
+            _ => normalized.extend_from_slice(trimmed),
+        }
+        normalized.extend_from_slice(b"\r\n");
+
+        match next {
+            Some(next) => rem = next,
+            None => {
+                warnings.push(ParseWarning::MissingFinalCrlf);
+                normalized.extend_from_slice(b"\r\n");
+                break;
+            }
+        }
+    }
+
+    (normalized, warnings)
+}
+
+fn normalize_start_line(line: &[u8], warnings: &mut Vec<ParseWarning>) -> Vec<u8> {
+    let mut line = line.to_vec();
+    let version_at = match is_lowercase_version(&line) {
+        true => Some(0),
+        false => line
+            .iter()
+            .rposition(|c| *c == b' ')
+            .map(|index| index + 1)
+            .filter(|index| is_lowercase_version(&line[*index..])),
+    };
+
+    if let Some(index) = version_at {
+        line[index..index + 3].copy_from_slice(b"SIP");
+        warnings.push(ParseWarning::LowercaseVersion);
+    }
+
+    line
+}
+
+fn is_lowercase_version(part: &[u8]) -> bool {
+    part.len() >= 4 && part[..4].eq_ignore_ascii_case(b"SIP/") && !part.starts_with(b"SIP/")
+}
+
+fn trim_end(line: &[u8]) -> &[u8] {
+    let len = line.len()
+        - line
+            .iter()
+            .rev()
+            .take_while(|c| **c == b' ' || **c == b'\t')
+            .count();
+
+    &line[..len]
+}
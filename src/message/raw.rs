@@ -0,0 +1,195 @@
+//! Writing messages back using the bytes they were parsed from, check [Headers] on what is kept.
+
+use crate::headers::{Header, Headers};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::{self, Write as _},
+    hash::Hasher,
+    io,
+    ops::Range,
+};
+
+/// The head (start line and headers) of a parsed message, as found in the bytes it was parsed
+/// from, along with a fingerprint of the start line and of each header it was parsed to, so
+/// that writing the message only has to compare those fingerprints with the current ones.
+#[derive(Clone)]
+pub(crate) struct Raw {
+    head: bytes::Bytes,
+    start_line: u64,
+    start_line_len: usize,
+    //the fingerprint of each parsed header, along with its span in the head
+    headers: Vec<(u64, Range<usize>)>,
+}
+
+impl Raw {
+    pub fn new(head: &[u8], start_line: &str, headers: Vec<(u64, Range<usize>)>) -> Self {
+        Self {
+            head: bytes::Bytes::copy_from_slice(head),
+            start_line: fingerprint(start_line),
+            start_line_len: find_crlf(head).unwrap_or(head.len()),
+            headers,
+        }
+    }
+
+    //replaces the fingerprints of the original headers that have been replaced, given as
+    //(previous, current) fingerprints in the order of the headers
+    pub fn remap(&mut self, fingerprints: Vec<(u64, u64)>) {
+        let mut remapped = vec![false; self.headers.len()];
+
+        for (previous, current) in fingerprints {
+            let original = self
+                .headers
+                .iter_mut()
+                .zip(remapped.iter_mut())
+                .find(|((fingerprint, _), remapped)| !**remapped && *fingerprint == previous);
+            if let Some(((fingerprint, _), remapped)) = original {
+                *fingerprint = current;
+                *remapped = true;
+            }
+        }
+    }
+}
+
+/// The span of a tokenized header (from the start of the name to the end of the value, without
+/// the CRLF) in the head it was tokenized from, if both the name and the value lie inside it.
+pub(crate) fn span(head: &[u8], name: &[u8], value: &[u8]) -> Option<Range<usize>> {
+    let start = offset(head, name)?;
+    let end = offset(head, value)? + value.len();
+
+    match start <= end {
+        true => Some(start..end),
+        false => None,
+    }
+}
+
+/// A fingerprint of what a header (or a start line) is written as, computed without allocating,
+/// used to find out if a parsed header has been modified since.
+pub(crate) fn fingerprint<T: fmt::Display + ?Sized>(value: &T) -> u64 {
+    let mut hasher = HashWriter(DefaultHasher::new());
+    //writing to a hasher can't fail
+    let _ = write!(hasher, "{}", value);
+
+    hasher.0.finish()
+}
+
+pub(crate) fn header_fingerprint(header: &Header) -> u64 {
+    match header {
+        Header::NonUtf8(name, value) => {
+            let mut hasher = DefaultHasher::new();
+            hasher.write(name.as_bytes());
+            hasher.write(value);
+            hasher.finish()
+        }
+        header => fingerprint(header),
+    }
+}
+
+/// A part of a message to be written, check [parts].
+pub(crate) enum Part<'a> {
+    Bytes(&'a [u8]),
+    Text(&'a str),
+    Header(&'a Header),
+}
+
+/// Walks over the parts of a message: the original bytes for the start line, if it is
+/// unchanged, and for any header that can be found, unmodified, among the original ones,
+/// otherwise what is currently there, followed by the body.
+pub(crate) fn parts<'a, E, F>(
+    start_line: &'a str,
+    headers: &'a Headers,
+    body: &'a [u8],
+    mut f: F,
+) -> Result<(), E>
+where
+    F: FnMut(Part<'a>) -> Result<(), E>,
+{
+    let raw = headers.raw();
+
+    match raw {
+        Some(raw) if raw.start_line == fingerprint(start_line) => {
+            f(Part::Bytes(&raw.head[..raw.start_line_len]))?
+        }
+        _ => f(Part::Text(start_line))?,
+    }
+    f(Part::Text("\r\n"))?;
+
+    let mut used = vec![false; raw.map(|raw| raw.headers.len()).unwrap_or_default()];
+    for header in headers.iter() {
+        let original = raw.and_then(|raw| {
+            let fingerprint = header_fingerprint(header);
+            raw.headers
+                .iter()
+                .zip(used.iter_mut())
+                .find(|((original, _), used)| !**used && *original == fingerprint)
+                .map(|((_, span), used)| {
+                    *used = true;
+                    &raw.head[span.clone()]
+                })
+        });
+
+        match original {
+            Some(original) => f(Part::Bytes(original))?,
+            None => f(Part::Header(header))?,
+        }
+        f(Part::Text("\r\n"))?;
+    }
+
+    f(Part::Text("\r\n"))?;
+    f(Part::Bytes(body))
+}
+
+/// Writes a message to the given writer, check [parts].
+pub(crate) fn write<W: io::Write>(
+    writer: &mut W,
+    start_line: &str,
+    headers: &Headers,
+    body: &[u8],
+) -> io::Result<()> {
+    parts(start_line, headers, body, |part| match part {
+        Part::Bytes(bytes) => writer.write_all(bytes),
+        Part::Text(text) => writer.write_all(text.as_bytes()),
+        Part::Header(Header::NonUtf8(name, value)) => {
+            write!(writer, "{}: ", name)?;
+            writer.write_all(value)
+        }
+        Part::Header(header) => write!(writer, "{}", header),
+    })
+}
+
+/// Same as [write], but for `Display`, which replaces any invalid UTF-8.
+pub(crate) fn fmt(
+    f: &mut fmt::Formatter<'_>,
+    start_line: &str,
+    headers: &Headers,
+    body: &[u8],
+) -> fmt::Result {
+    parts(start_line, headers, body, |part| match part {
+        Part::Bytes(bytes) => f.write_str(&String::from_utf8_lossy(bytes)),
+        Part::Text(text) => f.write_str(text),
+        Part::Header(header) => write!(f, "{}", header),
+    })
+}
+
+struct HashWriter(DefaultHasher);
+
+impl fmt::Write for HashWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+fn find_crlf(part: &[u8]) -> Option<usize> {
+    part.windows(2).position(|window| window == b"\r\n")
+}
+
+//the tokenized parts are slices of the head, so their position is found from their address,
+//checking that they really lie inside the head
+fn offset(head: &[u8], part: &[u8]) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(head.as_ptr() as usize)?;
+
+    match offset + part.len() <= head.len() {
+        true => Some(offset),
+        false => None,
+    }
+}
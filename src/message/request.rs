@@ -8,6 +8,7 @@ use crate::{
         version::{self, Version},
     },
     headers::{header, Headers},
+    message::raw,
     Error, SipMessage,
 };
 use std::convert::{TryFrom, TryInto};
@@ -18,7 +19,8 @@ use std::convert::{TryFrom, TryInto};
 ///
 /// A Request can easily be transformed to a [SipMessage](crate::SipMessage).
 /// Also it can be converted to a `String`, `&str`, or `Bytes`, all using the underlying `Debug`
-/// trait.
+/// trait. Binary bodies only survive the conversions to `Vec<u8>` and `Bytes` (or
+/// [Request::write_to]), as `to_string` replaces any invalid UTF-8.
 ///
 /// In order to access specific [headers](crate::headers::untyped), you should take a look on the
 /// [HeadersExt](crate::message::HeadersExt) trait that is automatically implemented for any type
@@ -53,6 +55,56 @@ impl Request {
     pub fn body_mut(&mut self) -> &mut Vec<u8> {
         &mut self.body
     }
+
+    /// Writes the request to the given writer, copying the body untouched, unlike `to_string`
+    /// which replaces any invalid UTF-8 in the body. To write into a
+    /// [BufMut](bytes::BufMut), use its [writer](bytes::BufMut::writer).
+    ///
+    /// If the request was parsed, the start line and the headers that have not been modified
+    /// since are written exactly as they were found.
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        raw::write(writer, &self.start_line(), &self.headers, &self.body)
+    }
+
+    fn start_line(&self) -> String {
+        format!("{} {} {}", self.method, self.uri, self.version)
+    }
+
+    //keeps the head the request has just been parsed from, check raw::Raw
+    pub(crate) fn keep_raw(&mut self, head: &[u8], spans: Vec<std::ops::Range<usize>>) {
+        let start_line = self.start_line();
+        self.headers.keep_raw(head, &start_line, spans);
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.body.len() + 512);
+        //writing to a Vec can't fail
+        self.write_to(&mut bytes).expect("write to vec");
+
+        bytes
+    }
+
+    /// Same as `to_string` but headers are written in their compact form, whenever they
+    /// have one. Use [Request::write_compact_to] to keep a binary body untouched.
+    pub fn to_compact_string(&self) -> String {
+        format!(
+            "{} {} {}\r\n{}\r\n{}",
+            self.method,
+            self.uri,
+            self.version,
+            self.headers.to_compact_string(),
+            String::from_utf8_lossy(&self.body)
+        )
+    }
+
+    /// Same as [Request::write_to] but headers are written in their compact form, whenever they
+    /// have one, and the original bytes of a parsed request are not used.
+    pub fn write_compact_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write!(writer, "{} {} {}\r\n", self.method, self.uri, self.version)?;
+        self.headers.write_compact_to(writer)?;
+        writer.write_all(b"\r\n")?;
+        writer.write_all(&self.body)
+    }
 }
 
 impl super::HasHeaders for Request {
@@ -67,15 +119,7 @@ impl super::HasHeaders for Request {
 
 impl std::fmt::Display for Request {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {} {}\r\n{}\r\n{}",
-            self.method,
-            self.uri,
-            self.version,
-            self.headers,
-            String::from_utf8_lossy(&self.body)
-        )
+        raw::fmt(f, &self.start_line(), &self.headers, &self.body)
     }
 }
 
@@ -95,8 +139,28 @@ impl TryFrom<SipMessage> for Request {
 impl TryFrom<&[u8]> for Request {
     type Error = Error;
 
+    //the default limits are enforced, SipMessage::parse_with allows other ones
     fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from)?.1.try_into()
+        crate::message::Limits::default().check(from)?;
+        Self::parse(from)
+    }
+}
+
+impl Request {
+    //parses without enforcing any limits
+    fn parse(from: &[u8]) -> Result<Self, Error> {
+        let tokenizer = Tokenizer::tokenize(from)
+            .map_err(|e| Error::located(e, from))?
+            .1;
+        let head = &from[..from.len() - tokenizer.body.len()];
+        let spans = tokenizer.spans(head);
+
+        let mut request: Self = tokenizer.try_into()?;
+        if let Some(spans) = spans {
+            request.keep_raw(head, spans);
+        }
+
+        Ok(request)
     }
 }
 
@@ -104,7 +168,7 @@ impl TryFrom<Vec<u8>> for Request {
     type Error = Error;
 
     fn try_from(from: Vec<u8>) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(&from)?.1.try_into()
+        Self::try_from(from.as_slice())
     }
 }
 
@@ -112,7 +176,7 @@ impl TryFrom<&str> for Request {
     type Error = Error;
 
     fn try_from(from: &str) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from.as_bytes())?.1.try_into()
+        Self::try_from(from.as_bytes())
     }
 }
 
@@ -120,7 +184,7 @@ impl TryFrom<String> for Request {
     type Error = Error;
 
     fn try_from(from: String) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from.as_bytes())?.1.try_into()
+        Self::try_from(from.as_bytes())
     }
 }
 
@@ -128,7 +192,7 @@ impl TryFrom<bytes::Bytes> for Request {
     type Error = Error;
 
     fn try_from(from: bytes::Bytes) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(&from)?.1.try_into()
+        Self::try_from(&from[..])
     }
 }
 
@@ -140,13 +204,19 @@ impl From<Request> for String {
 
 impl From<Request> for Vec<u8> {
     fn from(req: Request) -> Self {
-        req.to_string().into_bytes()
+        req.to_bytes()
     }
 }
 
 impl From<Request> for bytes::Bytes {
     fn from(req: Request) -> Self {
-        Self::from(req.to_string())
+        use bytes::BufMut;
+
+        let mut bytes = bytes::BytesMut::with_capacity(req.body.len() + 512).writer();
+        //writing to BytesMut can't fail
+        req.write_to(&mut bytes).expect("write to bytes");
+
+        bytes.into_inner().freeze()
     }
 }
 
@@ -185,6 +255,14 @@ pub mod tokenizer {
     }
 
     impl<'a> Tokenizer<'a> {
+        //the span of each header in the head they have been tokenized from, check raw::span
+        pub(crate) fn spans(&self, head: &[u8]) -> Option<Vec<std::ops::Range<usize>>> {
+            self.headers
+                .iter()
+                .map(|header| crate::message::raw::span(head, header.name, header.value))
+                .collect()
+        }
+
         pub fn tokenize(part: &'a [u8]) -> IResult<Self> {
             use crate::parser_utils::is_empty_or_fail_with;
             use nom::{
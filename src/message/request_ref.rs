@@ -0,0 +1,145 @@
+use crate::{
+    common::{
+        method::Method,
+        uri::{self, Uri},
+        version::Version,
+    },
+    headers::HeaderRef,
+    message::raw,
+    message::request::Tokenizer,
+    Error, Request,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// RequestRef is the borrowed counterpart of [Request](crate::Request): the headers and the
+/// body point to the bytes the request was parsed from, so parsing it allocates only a `Vec`
+/// to hold the headers. Useful for proxies and load balancers that only need to peek at a few
+/// headers before forwarding (or dropping) a message.
+///
+/// The [Uri] is kept tokenized and is only fully parsed when [RequestRef::uri] is called.
+/// Headers can be accessed through the [HeadersRefExt](crate::message::HeadersRefExt) trait.
+/// Whenever you need to modify the request, convert it to a [Request](crate::Request) using
+/// `TryFrom`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RequestRef<'a> {
+    method: Method,
+    uri: uri::Tokenizer<'a, &'a [u8], u8>,
+    version: Version,
+    headers: Vec<HeaderRef<'a>>,
+    body: &'a [u8],
+    //the start line and the headers, if parsed from bytes, so that they can be written exactly
+    //the same way once converted to an owned message
+    head: &'a [u8],
+}
+
+impl<'a> RequestRef<'a> {
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn uri(&self) -> Result<Uri, Error> {
+        self.uri.clone().try_into()
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn body(&self) -> &'a [u8] {
+        self.body
+    }
+}
+
+impl<'a> super::HasHeaderRefs<'a> for RequestRef<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>] {
+        &self.headers
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for RequestRef<'a> {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            method: tokenizer.method.try_into()?,
+            uri: tokenizer.uri,
+            version: tokenizer.version.try_into()?,
+            headers: tokenizer
+                .headers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, Error>>()?,
+            body: tokenizer.body,
+            head: &[],
+        })
+    }
+}
+
+impl<'a> RequestRef<'a> {
+    //same as converting the tokenizer, but also keeps the start line and the headers of the
+    //bytes it was tokenized from
+    pub(crate) fn with_head(tokenizer: Tokenizer<'a>, from: &'a [u8]) -> Result<Self, Error> {
+        let head = &from[..from.len() - tokenizer.body.len()];
+
+        Ok(Self {
+            head,
+            ..tokenizer.try_into()?
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RequestRef<'a> {
+    type Error = Error;
+
+    //the default limits are enforced, like the owned counterpart does
+    fn try_from(from: &'a [u8]) -> Result<Self, Self::Error> {
+        crate::message::Limits::default().check(from)?;
+        let tokenizer = Tokenizer::tokenize(from)
+            .map_err(|e| Error::located(e, from))?
+            .1;
+
+        Self::with_head(tokenizer, from)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for RequestRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(from.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<RequestRef<'a>> for Request {
+    type Error = Error;
+
+    fn try_from(from: RequestRef<'a>) -> Result<Self, Self::Error> {
+        //spans only make sense when the headers have been parsed from the head
+        let spans = match from.head.is_empty() {
+            true => None,
+            false => from
+                .headers
+                .iter()
+                .map(|header| raw::span(from.head, header.name.as_bytes(), header.value))
+                .collect::<Option<Vec<_>>>(),
+        };
+
+        let mut request = Request {
+            method: from.method,
+            uri: from.uri.try_into()?,
+            version: from.version,
+            headers: from
+                .headers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, Error>>()?
+                .into(),
+            body: from.body.into(),
+        };
+        if let Some(spans) = spans {
+            request.keep_raw(from.head, spans);
+        }
+
+        Ok(request)
+    }
+}
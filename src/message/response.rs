@@ -7,6 +7,7 @@ use crate::{
         version::{self, Version},
     },
     headers::{header, Headers},
+    message::raw,
     Error, SipMessage,
 };
 use std::convert::{TryFrom, TryInto};
@@ -17,7 +18,8 @@ use std::convert::{TryFrom, TryInto};
 ///
 /// A Response can easily be transformed to a [SipMessage](crate::SipMessage).
 /// Also it can be converted to a `String`, `&str`, or `Bytes`, all using the underlying `Debug`
-/// trait.
+/// trait. Binary bodies only survive the conversions to `Vec<u8>` and `Bytes` (or
+/// [Response::write_to]), as `to_string` replaces any invalid UTF-8.
 ///
 /// In order to access specific [headers](crate::headers::untyped), you should take a look on the
 /// [HeadersExt](crate::message::HeadersExt) trait that is automatically implemented for any type
@@ -47,6 +49,55 @@ impl Response {
     pub fn body_mut(&mut self) -> &mut Vec<u8> {
         &mut self.body
     }
+
+    /// Writes the response to the given writer, copying the body untouched, unlike `to_string`
+    /// which replaces any invalid UTF-8 in the body. To write into a
+    /// [BufMut](bytes::BufMut), use its [writer](bytes::BufMut::writer).
+    ///
+    /// If the response was parsed, the start line and the headers that have not been modified
+    /// since are written exactly as they were found.
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        raw::write(writer, &self.start_line(), &self.headers, &self.body)
+    }
+
+    fn start_line(&self) -> String {
+        format!("{} {}", self.version, self.status_code)
+    }
+
+    //keeps the head the response has just been parsed from, check raw::Raw
+    pub(crate) fn keep_raw(&mut self, head: &[u8], spans: Vec<std::ops::Range<usize>>) {
+        let start_line = self.start_line();
+        self.headers.keep_raw(head, &start_line, spans);
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.body.len() + 512);
+        //writing to a Vec can't fail
+        self.write_to(&mut bytes).expect("write to vec");
+
+        bytes
+    }
+
+    /// Same as `to_string` but headers are written in their compact form, whenever they
+    /// have one. Use [Response::write_compact_to] to keep a binary body untouched.
+    pub fn to_compact_string(&self) -> String {
+        format!(
+            "{} {}\r\n{}\r\n{}",
+            self.version,
+            self.status_code,
+            self.headers.to_compact_string(),
+            String::from_utf8_lossy(&self.body)
+        )
+    }
+
+    /// Same as [Response::write_to] but headers are written in their compact form, whenever they
+    /// have one, and the original bytes of a parsed response are not used.
+    pub fn write_compact_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write!(writer, "{} {}\r\n", self.version, self.status_code)?;
+        self.headers.write_compact_to(writer)?;
+        writer.write_all(b"\r\n")?;
+        writer.write_all(&self.body)
+    }
 }
 
 impl super::HasHeaders for Response {
@@ -61,14 +112,7 @@ impl super::HasHeaders for Response {
 
 impl std::fmt::Display for Response {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {}\r\n{}\r\n{}",
-            self.version,
-            self.status_code,
-            self.headers,
-            String::from_utf8_lossy(&self.body)
-        )
+        raw::fmt(f, &self.start_line(), &self.headers, &self.body)
     }
 }
 
@@ -88,8 +132,28 @@ impl TryFrom<SipMessage> for Response {
 impl TryFrom<&[u8]> for Response {
     type Error = Error;
 
+    //the default limits are enforced, SipMessage::parse_with allows other ones
     fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from)?.1.try_into()
+        crate::message::Limits::default().check(from)?;
+        Self::parse(from)
+    }
+}
+
+impl Response {
+    //parses without enforcing any limits
+    fn parse(from: &[u8]) -> Result<Self, Error> {
+        let tokenizer = Tokenizer::tokenize(from)
+            .map_err(|e| Error::located(e, from))?
+            .1;
+        let head = &from[..from.len() - tokenizer.body.len()];
+        let spans = tokenizer.spans(head);
+
+        let mut response: Self = tokenizer.try_into()?;
+        if let Some(spans) = spans {
+            response.keep_raw(head, spans);
+        }
+
+        Ok(response)
     }
 }
 
@@ -97,7 +161,7 @@ impl TryFrom<Vec<u8>> for Response {
     type Error = Error;
 
     fn try_from(from: Vec<u8>) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(&from)?.1.try_into()
+        Self::try_from(from.as_slice())
     }
 }
 
@@ -105,7 +169,7 @@ impl TryFrom<&str> for Response {
     type Error = Error;
 
     fn try_from(from: &str) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from.as_bytes())?.1.try_into()
+        Self::try_from(from.as_bytes())
     }
 }
 
@@ -113,7 +177,7 @@ impl TryFrom<String> for Response {
     type Error = Error;
 
     fn try_from(from: String) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from.as_bytes())?.1.try_into()
+        Self::try_from(from.as_bytes())
     }
 }
 
@@ -121,7 +185,7 @@ impl TryFrom<bytes::Bytes> for Response {
     type Error = Error;
 
     fn try_from(from: bytes::Bytes) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(&from)?.1.try_into()
+        Self::try_from(&from[..])
     }
 }
 
@@ -133,13 +197,19 @@ impl From<Response> for String {
 
 impl From<Response> for Vec<u8> {
     fn from(res: Response) -> Self {
-        res.to_string().into_bytes()
+        res.to_bytes()
     }
 }
 
 impl From<Response> for bytes::Bytes {
     fn from(res: Response) -> Self {
-        bytes::Bytes::from(res.to_string())
+        use bytes::BufMut;
+
+        let mut bytes = bytes::BytesMut::with_capacity(res.body.len() + 512).writer();
+        //writing to BytesMut can't fail
+        res.write_to(&mut bytes).expect("write to bytes");
+
+        bytes.into_inner().freeze()
     }
 }
 
@@ -176,6 +246,14 @@ pub mod tokenizer {
     }
 
     impl<'a> Tokenizer<'a> {
+        //the span of each header in the head they have been tokenized from, check raw::span
+        pub(crate) fn spans(&self, head: &[u8]) -> Option<Vec<std::ops::Range<usize>>> {
+            self.headers
+                .iter()
+                .map(|header| crate::message::raw::span(head, header.name, header.value))
+                .collect()
+        }
+
         pub fn tokenize(part: &'a [u8]) -> IResult<Self> {
             use crate::parser_utils::is_empty_or_fail_with;
             use nom::{
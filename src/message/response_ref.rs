@@ -0,0 +1,131 @@
+use crate::{
+    common::{status_code::StatusCode, version::Version},
+    headers::HeaderRef,
+    message::raw,
+    message::response::Tokenizer,
+    Error, Response,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// ResponseRef is the borrowed counterpart of [Response](crate::Response): the headers and the
+/// body point to the bytes the response was parsed from.
+///
+/// Headers can be accessed through the [HeadersRefExt](crate::message::HeadersRefExt) trait.
+/// Whenever you need to modify the response, convert it to a [Response](crate::Response) using
+/// `TryFrom`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ResponseRef<'a> {
+    status_code: StatusCode,
+    version: Version,
+    headers: Vec<HeaderRef<'a>>,
+    body: &'a [u8],
+    //the start line and the headers, if parsed from bytes, so that they can be written exactly
+    //the same way once converted to an owned message
+    head: &'a [u8],
+}
+
+impl<'a> ResponseRef<'a> {
+    pub fn status_code(&self) -> &StatusCode {
+        &self.status_code
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn body(&self) -> &'a [u8] {
+        self.body
+    }
+}
+
+impl<'a> super::HasHeaderRefs<'a> for ResponseRef<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>] {
+        &self.headers
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for ResponseRef<'a> {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status_code: tokenizer.status_code.try_into()?,
+            version: tokenizer.version.try_into()?,
+            headers: tokenizer
+                .headers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, Error>>()?,
+            body: tokenizer.body,
+            head: &[],
+        })
+    }
+}
+
+impl<'a> ResponseRef<'a> {
+    //same as converting the tokenizer, but also keeps the start line and the headers of the
+    //bytes it was tokenized from
+    pub(crate) fn with_head(tokenizer: Tokenizer<'a>, from: &'a [u8]) -> Result<Self, Error> {
+        let head = &from[..from.len() - tokenizer.body.len()];
+
+        Ok(Self {
+            head,
+            ..tokenizer.try_into()?
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ResponseRef<'a> {
+    type Error = Error;
+
+    //the default limits are enforced, like the owned counterpart does
+    fn try_from(from: &'a [u8]) -> Result<Self, Self::Error> {
+        crate::message::Limits::default().check(from)?;
+        let tokenizer = Tokenizer::tokenize(from)
+            .map_err(|e| Error::located(e, from))?
+            .1;
+
+        Self::with_head(tokenizer, from)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ResponseRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(from.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<ResponseRef<'a>> for Response {
+    type Error = Error;
+
+    fn try_from(from: ResponseRef<'a>) -> Result<Self, Self::Error> {
+        //spans only make sense when the headers have been parsed from the head
+        let spans = match from.head.is_empty() {
+            true => None,
+            false => from
+                .headers
+                .iter()
+                .map(|header| raw::span(from.head, header.name.as_bytes(), header.value))
+                .collect::<Option<Vec<_>>>(),
+        };
+
+        let mut response = Response {
+            status_code: from.status_code,
+            version: from.version,
+            headers: from
+                .headers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, Error>>()?
+                .into(),
+            body: from.body.into(),
+        };
+        if let Some(spans) = spans {
+            response.keep_raw(from.head, spans);
+        }
+
+        Ok(response)
+    }
+}
@@ -9,7 +9,8 @@ use std::convert::{TryFrom, TryInto};
 /// or a [Response](crate::Response).
 ///
 /// A SipMessage can be converted to a `String`, `&str`, or `Bytes`, all using the underlying `Debug`
-/// trait.
+/// trait. Binary bodies only survive the conversions to `Vec<u8>` and `Bytes` (or
+/// [SipMessage::write_to]), as `to_string` replaces any invalid UTF-8.
 ///
 /// A SipMessage can be taken using the `TryFrom` convertions from a `String`, an `&str` or
 /// `Bytes`, all using the underlying Tokenizer. If a convertion fails, the tokenizer will give you
@@ -54,6 +55,40 @@ impl SipMessage {
             Self::Response(response) => response.body_mut(),
         }
     }
+
+    /// Writes the message to the given writer, copying the body untouched, check
+    /// [Request::write_to].
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::Request(request) => request.write_to(writer),
+            Self::Response(response) => response.write_to(writer),
+        }
+    }
+
+    /// Same as `to_string` but headers are written in their compact form, whenever they
+    /// have one.
+    pub fn to_compact_string(&self) -> String {
+        match self {
+            Self::Request(request) => request.to_compact_string(),
+            Self::Response(response) => response.to_compact_string(),
+        }
+    }
+
+    /// Writes the message to the given writer, with headers in their compact form, check
+    /// [Request::write_compact_to].
+    pub fn write_compact_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::Request(request) => request.write_compact_to(writer),
+            Self::Response(response) => response.write_compact_to(writer),
+        }
+    }
+
+    pub(crate) fn keep_raw(&mut self, head: &[u8], spans: Vec<std::ops::Range<usize>>) {
+        match self {
+            Self::Request(request) => request.keep_raw(head, spans),
+            Self::Response(response) => response.keep_raw(head, spans),
+        }
+    }
 }
 
 impl From<Request> for SipMessage {
@@ -96,8 +131,28 @@ impl std::fmt::Display for SipMessage {
 impl TryFrom<&[u8]> for SipMessage {
     type Error = Error;
 
+    //the default limits are enforced, SipMessage::parse_with allows other ones
     fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from)?.1.try_into()
+        crate::message::Limits::default().check(from)?;
+        Self::parse(from)
+    }
+}
+
+impl SipMessage {
+    //parses without enforcing any limits
+    pub(crate) fn parse(from: &[u8]) -> Result<Self, Error> {
+        let tokenizer = Tokenizer::tokenize(from)
+            .map_err(|e| Error::located(e, from))?
+            .1;
+        let head = &from[..from.len() - tokenizer.body().len()];
+        let spans = tokenizer.spans(head);
+
+        let mut sip_message: Self = tokenizer.try_into()?;
+        if let Some(spans) = spans {
+            sip_message.keep_raw(head, spans);
+        }
+
+        Ok(sip_message)
     }
 }
 
@@ -105,7 +160,7 @@ impl TryFrom<Vec<u8>> for SipMessage {
     type Error = Error;
 
     fn try_from(from: Vec<u8>) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(&from)?.1.try_into()
+        Self::try_from(from.as_slice())
     }
 }
 
@@ -113,7 +168,7 @@ impl TryFrom<&str> for SipMessage {
     type Error = Error;
 
     fn try_from(from: &str) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from.as_bytes())?.1.try_into()
+        Self::try_from(from.as_bytes())
     }
 }
 
@@ -121,7 +176,7 @@ impl TryFrom<String> for SipMessage {
     type Error = Error;
 
     fn try_from(from: String) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(from.as_bytes())?.1.try_into()
+        Self::try_from(from.as_bytes())
     }
 }
 
@@ -129,7 +184,7 @@ impl TryFrom<bytes::Bytes> for SipMessage {
     type Error = Error;
 
     fn try_from(from: bytes::Bytes) -> Result<Self, Self::Error> {
-        Tokenizer::tokenize(&from)?.1.try_into()
+        Self::try_from(&from[..])
     }
 }
 
@@ -141,13 +196,23 @@ impl From<SipMessage> for String {
 
 impl From<SipMessage> for Vec<u8> {
     fn from(msg: SipMessage) -> Self {
-        msg.to_string().into_bytes()
+        let mut bytes = Vec::with_capacity(msg.body().len() + 512);
+        //writing to a Vec can't fail
+        msg.write_to(&mut bytes).expect("write to vec");
+
+        bytes
     }
 }
 
 impl From<SipMessage> for bytes::Bytes {
     fn from(msg: SipMessage) -> Self {
-        bytes::Bytes::from(msg.to_string())
+        use bytes::BufMut;
+
+        let mut bytes = bytes::BytesMut::with_capacity(msg.body().len() + 512).writer();
+        //writing to BytesMut can't fail
+        msg.write_to(&mut bytes).expect("write to bytes");
+
+        bytes.into_inner().freeze()
     }
 }
 
@@ -174,6 +239,22 @@ pub mod tokenizer {
         Response(response::Tokenizer<'a>),
     }
 
+    impl<'a> Tokenizer<'a> {
+        pub(crate) fn body(&self) -> &'a [u8] {
+            match self {
+                Self::Request(tokenizer) => tokenizer.body,
+                Self::Response(tokenizer) => tokenizer.body,
+            }
+        }
+
+        pub(crate) fn spans(&self, head: &[u8]) -> Option<Vec<std::ops::Range<usize>>> {
+            match self {
+                Self::Request(tokenizer) => tokenizer.spans(head),
+                Self::Response(tokenizer) => tokenizer.spans(head),
+            }
+        }
+    }
+
     impl<'a> From<request::Tokenizer<'a>> for Tokenizer<'a> {
         fn from(tokenizer: request::Tokenizer<'a>) -> Self {
             Self::Request(tokenizer)
@@ -0,0 +1,120 @@
+use crate::{
+    common::version::Version,
+    headers::HeaderRef,
+    message::{sip_message::Tokenizer, HasHeaderRefs, RequestRef, ResponseRef},
+    Error, SipMessage,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// The borrowed counterpart of [SipMessage](crate::SipMessage), holding either a
+/// [RequestRef] or a [ResponseRef].
+///
+/// ```
+/// use rsip::message::{HeadersRefExt, SipMessageRef};
+/// use std::convert::TryFrom;
+///
+/// let bytes = b"SIP/2.0 200 OK\r\nCall-ID: a84b4c76e66710\r\nContent-Length: 0\r\n\r\n";
+/// let message = SipMessageRef::try_from(&bytes[..]).unwrap();
+///
+/// assert_eq!(message.call_id_header().unwrap().value().unwrap(), "a84b4c76e66710");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SipMessageRef<'a> {
+    Request(RequestRef<'a>),
+    Response(ResponseRef<'a>),
+}
+
+impl<'a> SipMessageRef<'a> {
+    pub fn is_request(&self) -> bool {
+        matches!(self, Self::Request(_))
+    }
+
+    pub fn is_response(&self) -> bool {
+        matches!(self, Self::Response(_))
+    }
+
+    pub fn version(&self) -> &Version {
+        match self {
+            Self::Request(request) => request.version(),
+            Self::Response(response) => response.version(),
+        }
+    }
+
+    pub fn body(&self) -> &'a [u8] {
+        match self {
+            Self::Request(request) => request.body(),
+            Self::Response(response) => response.body(),
+        }
+    }
+}
+
+impl<'a> HasHeaderRefs<'a> for SipMessageRef<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>] {
+        match self {
+            Self::Request(request) => request.headers(),
+            Self::Response(response) => response.headers(),
+        }
+    }
+}
+
+impl<'a> From<RequestRef<'a>> for SipMessageRef<'a> {
+    fn from(request: RequestRef<'a>) -> Self {
+        Self::Request(request)
+    }
+}
+
+impl<'a> From<ResponseRef<'a>> for SipMessageRef<'a> {
+    fn from(response: ResponseRef<'a>) -> Self {
+        Self::Response(response)
+    }
+}
+
+impl<'a> TryFrom<Tokenizer<'a>> for SipMessageRef<'a> {
+    type Error = Error;
+
+    fn try_from(tokenizer: Tokenizer<'a>) -> Result<Self, Self::Error> {
+        match tokenizer {
+            Tokenizer::Request(tokenizer) => Ok(Self::Request(tokenizer.try_into()?)),
+            Tokenizer::Response(tokenizer) => Ok(Self::Response(tokenizer.try_into()?)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SipMessageRef<'a> {
+    type Error = Error;
+
+    //the default limits are enforced, like the owned counterpart does
+    fn try_from(from: &'a [u8]) -> Result<Self, Self::Error> {
+        crate::message::Limits::default().check(from)?;
+        match Tokenizer::tokenize(from)
+            .map_err(|e| Error::located(e, from))?
+            .1
+        {
+            Tokenizer::Request(tokenizer) => {
+                Ok(Self::Request(RequestRef::with_head(tokenizer, from)?))
+            }
+            Tokenizer::Response(tokenizer) => {
+                Ok(Self::Response(ResponseRef::with_head(tokenizer, from)?))
+            }
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SipMessageRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(from.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<SipMessageRef<'a>> for SipMessage {
+    type Error = Error;
+
+    fn try_from(from: SipMessageRef<'a>) -> Result<Self, Self::Error> {
+        match from {
+            SipMessageRef::Request(request) => Ok(SipMessage::Request(request.try_into()?)),
+            SipMessageRef::Response(response) => Ok(SipMessage::Response(response.try_into()?)),
+        }
+    }
+}
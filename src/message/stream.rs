@@ -0,0 +1,146 @@
+//! Helpers for reading SIP messages out of stream transports, like TCP or TLS.
+//!
+//! On a stream, a message does not arrive in a single packet: a read might give you half a
+//! message, or one and a half messages. The only way to find where a message ends is the
+//! `Content-Length` header, which is mandatory on streams according to
+//! [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-18.3).
+
+use crate::{
+    message::{limits::exceeds, HeadersExt, Limit, Limits},
+    Error, SipMessage,
+};
+use std::convert::TryInto;
+
+/// The outcome of [decode].
+///
+/// * `Complete` holds a complete [SipMessage] along with the number of bytes it consumed from
+///   the buffer (including any keep-alives preceding it). Those bytes should be dropped from the
+///   buffer before calling [decode] again.
+/// * `Incomplete` means that the buffer does not hold a whole message yet and more bytes need
+///   to be read from the stream.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Decoded {
+    Complete(SipMessage, usize),
+    Incomplete,
+}
+
+/// Tries to decode a single [SipMessage] from the beginning of a stream buffer.
+///
+/// CRLF keep-alives ([RFC5626](https://datatracker.ietf.org/doc/html/rfc5626#section-4.4.1))
+/// found before the message are skipped. The body is taken using the `Content-Length` header,
+/// anything after it is left in the buffer for the next message. A missing `Content-Length`
+/// header results in an [Error::MissingHeader] error.
+///
+/// The given [Limits] are enforced as soon as possible, so that a peer can't make you buffer
+/// an endless message: an [Error::LimitExceeded] is returned once the headers grow beyond
+/// `max_message_size` (or break any other limit when complete), or when the `Content-Length`
+/// is beyond `max_body_size` (or makes the message beyond `max_message_size`). The stream
+/// should be closed in that case, as there is no way to tell where the next message starts.
+///
+/// Each call scans the whole buffer again, use a [Decoder] to avoid that while waiting for more
+/// bytes.
+pub fn decode(part: &[u8], limits: &Limits) -> Result<Decoded, Error> {
+    Decoder::new(*limits).decode(part)
+}
+
+/// Same as [decode], but keeps track of how far the buffer has been decoded between calls,
+/// so that each call only looks at the bytes that have been added since the previous one: the
+/// end of the headers is searched from where the previous call stopped, and the headers are
+/// parsed only once, even if the body takes several reads to arrive.
+///
+/// The buffer given to [Decoder::decode] is expected to be the same one, with more bytes added
+/// at its end, until a `Complete` message is returned. Then the consumed bytes should be
+/// dropped from the buffer and the decoder starts over with the next message.
+#[derive(Debug, Default, Clone)]
+pub struct Decoder {
+    limits: Limits,
+    //how far the buffer has been searched for the end of the headers
+    scanned: usize,
+    //the message whose headers have been parsed, along with where its body starts and ends
+    pending: Option<(SipMessage, usize, usize)>,
+}
+
+impl Decoder {
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            ..Default::default()
+        }
+    }
+
+    /// Tries to decode a single [SipMessage] from the beginning of the buffer, check [decode].
+    pub fn decode(&mut self, part: &[u8]) -> Result<Decoded, Error> {
+        if self.pending.is_none() {
+            self.pending = self.decode_head(part)?;
+        }
+
+        match self.pending.take() {
+            Some((mut message, body_start, body_end)) if part.len() >= body_end => {
+                *message.body_mut() = part[body_start..body_end].to_vec();
+                self.scanned = 0;
+
+                Ok(Decoded::Complete(message, body_end))
+            }
+            pending => {
+                self.pending = pending;
+                Ok(Decoded::Incomplete)
+            }
+        }
+    }
+
+    fn decode_head(&mut self, part: &[u8]) -> Result<Option<(SipMessage, usize, usize)>, Error> {
+        let start = keep_alives_len(part);
+
+        //the end of the headers might be split between the previous bytes and the new ones
+        let from = self.scanned.saturating_sub(3).max(start);
+        let headers_len = match find(&part[from..], b"\r\n\r\n") {
+            Some(index) => from - start + index + 4,
+            None => {
+                self.scanned = part.len();
+                exceeds(
+                    Limit::MessageSize,
+                    part.len() - start,
+                    self.limits.max_message_size,
+                )?;
+                return Ok(None);
+            }
+        };
+        let body_start = start + headers_len;
+
+        let head = &part[start..body_start];
+        self.limits.check(head)?;
+
+        let tokenizer = crate::message::sip_message::Tokenizer::tokenize(head)
+            .map_err(|e| Error::located(e, &part[start..]))?
+            .1;
+        let spans = tokenizer.spans(head);
+
+        let mut message: SipMessage = tokenizer.try_into()?;
+        if let Some(spans) = spans {
+            message.keep_raw(head, spans);
+        }
+
+        let content_length = message
+            .content_length_header()
+            .ok_or_else(|| Error::missing_header("Content-Length"))?
+            .length()? as usize;
+        exceeds(Limit::BodySize, content_length, self.limits.max_body_size)?;
+        exceeds(
+            Limit::MessageSize,
+            headers_len.saturating_add(content_length),
+            self.limits.max_message_size,
+        )?;
+
+        Ok(Some((message, body_start, body_start + content_length)))
+    }
+}
+
+fn keep_alives_len(part: &[u8]) -> usize {
+    part.chunks(2).take_while(|chunk| chunk == b"\r\n").count() * 2
+}
+
+fn find(part: &[u8], needle: &[u8]) -> Option<usize> {
+    part.windows(needle.len())
+        .position(|window| window == needle)
+}
@@ -3,5 +3,14 @@
 
 #[doc(hidden)]
 pub mod digest_generator;
+#[doc(hidden)]
+pub mod prack;
+#[doc(hidden)]
+pub mod privacy;
+#[doc(hidden)]
+pub mod session_timer;
 
 pub use digest_generator::DigestGenerator;
+pub use prack::prack;
+pub use privacy::apply_privacy;
+pub use session_timer::SessionTimer;
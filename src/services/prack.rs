@@ -0,0 +1,89 @@
+use crate::{
+    common::uri::{param::Branch, Param},
+    headers::{self, typed, untyped::ToTypedHeader, UntypedHeader},
+    message::HeadersExt,
+    Error, Header, Method, Request, Response,
+};
+
+/// Builds the PRACK that acknowledges a reliable provisional response, as defined in
+/// [RFC3262](https://datatracker.ietf.org/doc/html/rfc3262#section-4), out of the response
+/// itself and the INVITE it responds to. The response must be reliable, that is, hold `100rel`
+/// in its `Require` header and an `RSeq` header.
+///
+/// The PRACK is sent inside the early dialog that the response creates:
+///
+/// * the Request-URI is the `Contact` of the response (or the INVITE Request-URI if the response
+///   has none) and the route set is the `Record-Route` of the response, in reverse order
+/// * `From` and `Call-ID` are copied from the INVITE, while `To` (which holds the remote tag) is
+///   copied from the response
+/// * `CSeq` is the one of the INVITE incremented by one. If other requests have been sent in the
+///   dialog since then, the `CSeq` should be updated accordingly
+/// * `RAck` holds the `RSeq` of the response along with its `CSeq`
+/// * the top `Via` of the INVITE is used, with a new branch, as the PRACK is a new transaction
+pub fn prack(invite: &Request, response: &Response) -> Result<Request, Error> {
+    let code = response.status_code.code();
+    if !(101..200).contains(&code) {
+        return Err(Error::Unexpected(format!(
+            "PRACK needs a provisional response, got {}",
+            response.status_code
+        )));
+    }
+
+    let reliable = response.headers.values().any(|header| match header {
+        Header::Require(require) => require.value() == "100rel",
+        _ => false,
+    });
+    if !reliable {
+        return Err(Error::Unexpected(
+            "PRACK needs a reliable response, with 100rel in its Require header".into(),
+        ));
+    }
+
+    let rseq = response
+        .rseq_header()
+        .ok_or_else(|| Error::missing_header("RSeq"))?
+        .typed()?;
+    let response_cseq = response.cseq_header()?.typed()?;
+    let invite_cseq = invite.cseq_header()?.typed()?;
+
+    let uri = match response.contact_header() {
+        Ok(contact) => contact.typed()?.uri,
+        Err(_) => invite.uri.clone(),
+    };
+
+    let mut via = invite.via_header()?.typed()?;
+    via.params
+        .retain(|param| !matches!(param, Param::Branch(_)));
+    via.params.push(Param::Branch(Branch::default()));
+
+    let mut headers: Vec<Header> = vec![via.into()];
+    headers.extend(
+        response
+            .record_route_values()
+            .into_iter()
+            .rev()
+            .map(|record_route| headers::Route::new(record_route.value()).into()),
+    );
+    headers.push(headers::MaxForwards::default().into());
+    headers.push(response.to_header()?.clone().into());
+    headers.push(invite.from_header()?.clone().into());
+    headers.push(invite.call_id_header()?.clone().into());
+    headers.push(typed::CSeq::from((invite_cseq.seq + 1, Method::PRack)).into());
+    headers.push(
+        typed::RAck {
+            rseq: rseq.0,
+            cseq: response_cseq.seq,
+            method: response_cseq.method,
+        }
+        .into(),
+    );
+    headers.push(headers::ContentLength::default().into());
+
+    Ok(Request {
+        method: Method::PRack,
+        uri,
+        version: invite.version.clone(),
+        headers: headers.into(),
+        body: vec![],
+    })
+}
@@ -0,0 +1,93 @@
+use crate::{
+    common::uri::{Param, Uri},
+    headers::{
+        typed::{self, privacy::PrivacyValue},
+        untyped::ToTypedHeader,
+    },
+    message::HeadersExt,
+    Error, Header, Request,
+};
+use std::convert::TryFrom;
+
+/// Applies the privacy requested in the `Privacy` header of a request, as a privacy service
+/// would according to [RFC3323](https://datatracker.ietf.org/doc/html/rfc3323#section-5) and
+/// [RFC3325](https://datatracker.ietf.org/doc/html/rfc3325#section-7):
+///
+/// * for `user` or `header` privacy, `From` is replaced by
+///   `"Anonymous" <sip:anonymous@anonymous.invalid>` (keeping its tag) and the headers that
+///   carry information about the user (`Call-Info`, `In-Reply-To`, `Organization`, `Reply-To`,
+///   `Subject` and `User-Agent`) are removed
+/// * for `id` privacy, `P-Asserted-Identity` is removed, which is meant to take place when
+///   the request leaves the trust domain
+/// * `P-Preferred-Identity` is removed for any of the above
+///
+/// Nothing changes if there is no `Privacy` header or it is `none`. The `Privacy` header itself
+/// is kept, so that any following hop knows about it. Anonymizing `Via`, `Contact` and
+/// `Record-Route`, which `header` privacy asks for as well, requires a stateful service and is
+/// left to the caller, so `header` privacy is only partly applied. Neither is `session` privacy
+/// (or any unknown type) applied, as it concerns the media.
+///
+/// When `critical` is present and any of the requested types is not fully applied (`header`,
+/// `session` or an unknown one), the request is left untouched and an [Error::Unexpected] is
+/// returned, as the request should be rejected according to
+/// [RFC3323](https://datatracker.ietf.org/doc/html/rfc3323#section-5).
+pub fn apply_privacy(request: &mut Request) -> Result<(), Error> {
+    let privacy = match request.privacy_header() {
+        Some(privacy) => privacy.typed()?,
+        None => return Ok(()),
+    };
+    if !privacy.is_requested() {
+        return Ok(());
+    }
+
+    if privacy.contains(&PrivacyValue::Critical) {
+        let partial = privacy
+            .0
+            .iter()
+            .filter(|value| {
+                matches!(
+                    value,
+                    PrivacyValue::Header | PrivacyValue::Session | PrivacyValue::Other(_)
+                )
+            })
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        if !partial.is_empty() {
+            return Err(Error::Unexpected(format!(
+                "critical privacy can't be fully applied: {}",
+                partial.join(";")
+            )));
+        }
+    }
+
+    let user = privacy.contains(&PrivacyValue::User) || privacy.contains(&PrivacyValue::Header);
+    let id = privacy.contains(&PrivacyValue::Id);
+
+    if user {
+        let from = request.from_header()?.typed()?;
+        let anonymous = typed::From {
+            display_name: Some("\"Anonymous\"".into()),
+            uri: Uri::try_from("sip:anonymous@anonymous.invalid")?,
+            params: from
+                .params
+                .into_iter()
+                .filter(|param| matches!(param, Param::Tag(_)))
+                .collect(),
+        };
+        *request.from_header_mut()? = anonymous.into();
+    }
+
+    request.headers.retain(|header| match header {
+        Header::CallInfo(_)
+        | Header::InReplyTo(_)
+        | Header::Organization(_)
+        | Header::ReplyTo(_)
+        | Header::Subject(_)
+        | Header::UserAgent(_) => !user,
+        Header::PAssertedIdentity(_) => !id,
+        Header::PPreferredIdentity(_) => !(user || id),
+        _ => true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use crate::{
+    headers::{
+        typed::{session_expires::Refresher, MinSe},
+        untyped::ToTypedHeader,
+    },
+    message::HeadersExt,
+    Error, Header, Request, Response, StatusCode, StatusCodeKind,
+};
+
+/// Helpful struct to work with session timers, as defined in
+/// [RFC4028](https://datatracker.ietf.org/doc/html/rfc4028). It holds the session interval (in
+/// seconds) negotiated for a dialog, along with the side of the dialog that refreshes it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SessionTimer {
+    pub interval: u32,
+    pub refresher: Refresher,
+}
+
+impl SessionTimer {
+    /// Works out the session timer from an INVITE (or UPDATE) and its 2xx response, as described
+    /// in [RFC4028](https://datatracker.ietf.org/doc/html/rfc4028#section-7.2). Returns `None`
+    /// if the response has no `Session-Expires` header, in which case the session does not
+    /// expire.
+    ///
+    /// The UAS always sets the `refresher` param in a 2xx response. If it is missing anyway, the
+    /// UAC is picked as the refresher, given that it is the side that asked for a session timer,
+    /// falling back to the `refresher` found in the request, if any.
+    pub fn negotiate(request: &Request, response: &Response) -> Result<Option<Self>, Error> {
+        if response.status_code.kind() != StatusCodeKind::Successful {
+            return Err(Error::Unexpected(format!(
+                "session timer needs a 2xx response, got {}",
+                response.status_code
+            )));
+        }
+
+        let session_expires = match response.session_expires_header() {
+            Some(session_expires) => session_expires.typed()?,
+            None => return Ok(None),
+        };
+
+        let refresher = match session_expires.refresher {
+            Some(refresher) => refresher,
+            None => match request.session_expires_header() {
+                Some(requested) => requested.typed()?.refresher.unwrap_or(Refresher::Uac),
+                None => Refresher::Uac,
+            },
+        };
+
+        Ok(Some(Self {
+            interval: session_expires.seconds,
+            refresher,
+        }))
+    }
+
+    /// Checks the `Session-Expires` header of an incoming request against the minimum session
+    /// interval of the UAS (or proxy), returning the `422 Session Interval Too Small` response
+    /// that should be sent back when the requested interval is smaller, check
+    /// [SessionTimer::interval_too_small].
+    pub fn check_interval(request: &Request, min_se: u32) -> Result<Option<Response>, Error> {
+        match request.session_expires_header() {
+            Some(session_expires) if session_expires.typed()?.seconds < min_se => {
+                Ok(Some(Self::interval_too_small(request, min_se)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Builds a `422 Session Interval Too Small` response to the given request, carrying a
+    /// `Min-SE` header with the given minimum session interval.
+    pub fn interval_too_small(request: &Request, min_se: u32) -> Response {
+        let mut headers = request
+            .headers
+            .iter()
+            .filter(|header| {
+                matches!(
+                    header,
+                    Header::Via(_)
+                        | Header::From(_)
+                        | Header::To(_)
+                        | Header::CallId(_)
+                        | Header::CSeq(_)
+                )
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        headers.push(MinSe::new(min_se).into());
+        headers.push(crate::headers::ContentLength::default().into());
+
+        Response {
+            status_code: StatusCode::SessionIntervalTooSmall,
+            version: request.version.clone(),
+            headers: headers.into(),
+            body: vec![],
+        }
+    }
+
+    /// The session should be refreshed once half of the interval has passed, as
+    /// [RFC4028](https://datatracker.ietf.org/doc/html/rfc4028#section-10) recommends.
+    pub fn refresh_after(&self) -> u32 {
+        self.interval / 2
+    }
+}
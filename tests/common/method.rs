@@ -8,6 +8,14 @@ mod display {
     fn display1() {
         assert_eq!(Method::Register.to_string(), String::from("REGISTER"));
     }
+
+    #[test]
+    fn display2() {
+        assert_eq!(
+            Method::Other("X-Vendor".into()).to_string(),
+            String::from("X-Vendor")
+        );
+    }
 }
 
 mod parser {
@@ -29,6 +37,26 @@ mod parser {
         );
     }
 
+    #[test]
+    fn parser3() {
+        assert_eq!(
+            Tokenizer::from("X-Vendor".as_bytes()).try_into(),
+            Ok(Method::Other("X-Vendor".into())),
+        );
+    }
+
+    #[test]
+    fn parser4() {
+        use std::str::FromStr;
+
+        assert_eq!(Method::from_str("notify"), Ok(Method::Notify));
+        assert_eq!(
+            Method::from_str("REGISTE"),
+            Ok(Method::Other("REGISTE".into()))
+        );
+        assert!(!Method::all().contains(&Method::Other("REGISTE".into())));
+    }
+
     #[test]
     fn errors1() {
         use std::convert::TryFrom;
@@ -38,6 +66,16 @@ mod parser {
             Err(rsip::Error::ParseError("invalid method: REGI STER".into())),
         );
     }
+
+    #[test]
+    fn errors2() {
+        use std::convert::TryFrom;
+
+        assert_eq!(
+            Method::try_from(Tokenizer::from("".as_bytes())),
+            Err(rsip::Error::ParseError("invalid method: ".into())),
+        );
+    }
 }
 
 mod tokenizer {
@@ -7,10 +7,7 @@ mod display {
     #[test]
     fn display1() {
         assert_eq!(
-            Auth {
-                user: "user".into(),
-                password: None
-            }
+            Auth::from(("user", Option::<String>::None))
             .to_string(),
             String::from("user")
         );
@@ -19,14 +16,32 @@ mod display {
     #[test]
     fn display2() {
         assert_eq!(
-            Auth {
-                user: "user".into(),
-                password: Some("password".into())
-            }
+            Auth::from(("user", Some("password")))
             .to_string(),
             String::from("user:password")
         );
     }
+
+    #[test]
+    fn display3() {
+        let auth = Auth::from(("alice smith", Some("p@ss:word")));
+
+        assert_eq!(auth.escaped_user(), "alice%20smith");
+        assert_eq!(auth.escaped_password(), Some("p%40ss%3Aword".into()));
+        assert_eq!(
+            auth.to_string(),
+            String::from("alice%20smith:p%40ss%3Aword")
+        );
+    }
+
+    #[test]
+    fn display4() {
+        assert_eq!(
+            Auth::from(("+1#234;isub=55", Option::<String>::None))
+            .to_string(),
+            String::from("+1%23234;isub=55")
+        );
+    }
 }
 
 mod parser {
@@ -36,12 +51,61 @@ mod parser {
     fn parser() {
         assert_eq!(
             Tokenizer::from(("user".as_bytes(), Some("password".as_bytes()))).try_into(),
-            Ok(Auth {
-                user: "user".into(),
-                password: Some("password".into())
-            }),
+            Ok(Auth::from(("user", Some("password")))),
+        );
+    }
+
+    #[test]
+    fn parser2() {
+        assert_eq!(
+            Tokenizer::from(("alice%20smith".as_bytes(), Some("p%40ss".as_bytes()))).try_into(),
+            Ok(Auth::from(("alice smith", Some("p@ss")))),
         );
     }
+
+    #[test]
+    fn parser3() {
+        for user in ["alice%2", "a%+5", "j%F6rg"] {
+            let auth: Auth = Tokenizer::from((user.as_bytes(), None)).try_into().unwrap();
+            assert_eq!(auth.user, user);
+            assert_eq!(auth.to_string(), user);
+        }
+    }
+
+    #[test]
+    fn parser4() {
+        let auth: Auth = Tokenizer::from(("%61lice".as_bytes(), Some("p%61ss".as_bytes())))
+            .try_into()
+            .unwrap();
+        assert_eq!(auth, Auth::from(("alice", Some("pass"))));
+        assert_eq!(auth.raw(), Some("%61lice:p%61ss"));
+        assert_eq!(auth.to_string(), "%61lice:p%61ss");
+
+        let mut auth = auth;
+        auth.user = "bob smith".into();
+        assert_eq!(auth.to_string(), "bob%20smith:p%61ss");
+    }
+}
+
+mod raw {
+    use super::*;
+
+    #[test]
+    fn from_escaped() {
+        let auth = Auth::from_escaped("%61lice%20smith", Some("p%40ss"));
+
+        assert_eq!(auth, Auth::from(("alice smith", Some("p@ss"))));
+        assert_eq!(auth.raw(), Some("%61lice%20smith:p%40ss"));
+        assert_eq!(auth.to_string(), "%61lice%20smith:p%40ss");
+    }
+
+    #[test]
+    fn not_parsed() {
+        let auth = Auth::from(("alice smith", Option::<String>::None));
+
+        assert_eq!(auth.raw(), None);
+        assert_eq!(auth.to_string(), "alice%20smith");
+    }
 }
 
 mod tokenizer {
@@ -70,14 +134,20 @@ mod tokenizer {
     fn tokenizer1_str2() {
         assert_eq!(
             Tokenizer::tokenize("user:&=+$,password@server2.com something"),
-            Ok(("server2.com something", ("user", Some("&=+$,password")).into())),
+            Ok((
+                "server2.com something",
+                ("user", Some("&=+$,password")).into()
+            )),
         );
     }
     #[test]
     fn tokenizer1_str3() {
         assert_eq!(
             Tokenizer::tokenize("-_.!~*'/user&=+$,:password@server2.com something"),
-            Ok(("server2.com something", ("-_.!~*'/user&=+$,", Some("password")).into())),
+            Ok((
+                "server2.com something",
+                ("-_.!~*'/user&=+$,", Some("password")).into()
+            )),
         );
     }
 
@@ -96,7 +166,7 @@ mod tokenizer {
         assert_eq!(
             Tokenizer::tokenize("@server2.com something"),
             Err(nom::Err::Error(rsip::TokenizerError::from(
-                "failed to tokenize auth @server2.com something"
+                "failed to tokenize auth: @server2.com something"
             ))),
         );
     }
@@ -106,8 +176,8 @@ mod tokenizer {
         assert_eq!(
             Tokenizer::tokenize("user::password@server2.com something"),
             Err(nom::Err::Error(rsip::TokenizerError::from(
-                "failed to tokenize auth @server2.com something"
+                "failed to tokenize auth: @server2.com something"
             ))),
         );
     }
-}
\ No newline at end of file
+}
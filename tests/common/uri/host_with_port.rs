@@ -1,5 +1,6 @@
 use rsip::common::uri::{host_with_port::Tokenizer, Host, HostWithPort};
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use std::net::IpAddr;
 
 mod display {
     use super::*;
@@ -27,6 +28,39 @@ mod display {
             String::from("server2.com:5060")
         );
     }
+
+    #[test]
+    fn display3() {
+        assert_eq!(
+            HostWithPort::from(("2001:db8::10".parse::<IpAddr>().unwrap(), 5070)).to_string(),
+            String::from("[2001:db8::10]:5070")
+        );
+        assert_eq!(
+            HostWithPort::from("::1".parse::<IpAddr>().unwrap()).to_string(),
+            String::from("[::1]")
+        );
+    }
+}
+
+mod equivalent {
+    use super::*;
+
+    #[test]
+    fn equivalent1() {
+        assert!(HostWithPort::try_from("AtLanTa.CoM:5060")
+            .unwrap()
+            .equivalent(&HostWithPort::try_from("atlanta.com:5060").unwrap()));
+    }
+
+    #[test]
+    fn equivalent2() {
+        assert!(!HostWithPort::try_from("atlanta.com")
+            .unwrap()
+            .equivalent(&HostWithPort::try_from("atlanta.com:5060").unwrap()));
+        assert!(!HostWithPort::try_from("192.0.2.4")
+            .unwrap()
+            .equivalent(&HostWithPort::try_from("atlanta.com").unwrap()));
+    }
 }
 
 mod parser {
@@ -53,6 +87,46 @@ mod parser {
             })
         );
     }
+
+    #[test]
+    fn parser3() {
+        assert_eq!(
+            Tokenizer::from(("[2001:db8::10]".as_bytes(), Some("5070".as_bytes()))).try_into(),
+            Ok(HostWithPort {
+                host: Host::IpAddr("2001:db8::10".parse().unwrap()),
+                port: Some(5070.into())
+            })
+        );
+    }
+
+    #[test]
+    fn parser4() {
+        assert!(TryInto::<HostWithPort>::try_into(Tokenizer::from((
+            "[atlanta.com]".as_bytes(),
+            None
+        )))
+        .is_err());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            HostWithPort::try_from("[2001:db8::10]:5070"),
+            Ok(HostWithPort {
+                host: Host::IpAddr("2001:db8::10".parse().unwrap()),
+                port: Some(5070.into())
+            })
+        );
+        assert_eq!(
+            HostWithPort::try_from("[::1]"),
+            Ok(HostWithPort {
+                host: Host::IpAddr("::1".parse().unwrap()),
+                port: None
+            })
+        );
+        assert!(HostWithPort::try_from("[::1]5060").is_err());
+        assert!(HostWithPort::try_from("[::1").is_err());
+    }
 }
 
 mod tokenizer {
@@ -97,6 +171,22 @@ mod tokenizer {
         );
     }
 
+    #[test]
+    fn tokenizer3_str() {
+        assert_eq!(
+            Tokenizer::tokenize("[2001:db8::10]:5070;transport=tcp"),
+            Ok((";transport=tcp", ("[2001:db8::10]", Some("5070")).into())),
+        );
+    }
+
+    #[test]
+    fn tokenizer3_u8() {
+        assert_eq!(
+            Tokenizer::tokenize("[::1] something".as_bytes()),
+            Ok((" something".as_bytes(), ("[::1]".as_bytes(), None).into())),
+        );
+    }
+
     #[test]
     fn errors1() {
         assert_eq!(
@@ -2,6 +2,7 @@ pub mod auth;
 pub mod host_with_port;
 pub mod params;
 pub mod scheme;
+pub mod tel_uri;
 pub mod uri_with_params;
 pub mod uri_with_params_list;
 
@@ -106,7 +107,8 @@ mod display {
                 auth: None,
                 host_with_port: ("server2.com", Option::<u16>::None).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             }
             .to_string(),
             String::from("server2.com")
@@ -121,7 +123,8 @@ mod display {
                 auth: Some(("user", Option::<String>::None).into()),
                 host_with_port: ("server2.com", Option::<u16>::None).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             }
             .to_string(),
             String::from("user@server2.com")
@@ -136,7 +139,8 @@ mod display {
                 auth: Some(("user", Some("password")).into()),
                 host_with_port: ("server2.com", Option::<u16>::None).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             }
             .to_string(),
             String::from("user:password@server2.com")
@@ -151,7 +155,8 @@ mod display {
                 auth: Some(("user", Some("password")).into()),
                 host_with_port: ("server2.com", Some(5060)).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             }
             .to_string(),
             String::from("user:password@server2.com:5060")
@@ -169,7 +174,8 @@ mod display {
                     Param::Maddr(Maddr::new("255.255.255.0")),
                     Param::Other("foo".into(), Some("192.0.2.201".into())),
                 ],
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             }
             .to_string(),
             String::from("sips:client.biloxi.example.com:5061;maddr=255.255.255.0;foo=192.0.2.201")
@@ -197,7 +203,8 @@ mod parser {
                 auth: None,
                 host_with_port: ("server2.com", Option::<u16>::None).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -219,7 +226,8 @@ mod parser {
                 auth: Some(("user", Option::<String>::None).into()),
                 host_with_port: ("server2.com", Option::<u16>::None).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -241,7 +249,8 @@ mod parser {
                 auth: Some(("user", Some("password")).into()),
                 host_with_port: ("server2.com", Option::<u16>::None).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -263,7 +272,8 @@ mod parser {
                 auth: Some(("user", Some("password")).into()),
                 host_with_port: ("server2.com", Some(5060)).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -285,7 +295,8 @@ mod parser {
                 auth: Some(("user", Option::<String>::None).into()),
                 host_with_port: ("server2.com", Some(5060)).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -307,7 +318,8 @@ mod parser {
                 auth: Some(("user", Some("password")).into()),
                 host_with_port: ("server2.com", Some(5060)).into(),
                 params: Default::default(),
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -339,7 +351,8 @@ mod parser {
                     Param::Maddr(Maddr::new("255.255.255.0")),
                     Param::Other("foo".into(), Some("192.0.2.201".into())),
                 ],
-                headers: Default::default()
+                headers: Default::default(),
+                opaque: None
             })
         );
     }
@@ -790,14 +803,404 @@ mod tokenizer {
 
 mod uri_comparison {
     use std::convert::TryFrom;
-    use rsip::Scheme::Sip;
     use super::*;
 
+    fn equivalent(uri: &str, other: &str) -> bool {
+        let uri = Uri::try_from(uri).unwrap();
+        let other = Uri::try_from(other).unwrap();
+
+        assert_eq!(uri.equivalent(&other), other.equivalent(&uri));
+        uri.equivalent(&other)
+    }
+
     #[test]
     fn rfc1() {
+        assert!(equivalent(
+            "sip:%61lice@atlanta.com;transport=TCP something",
+            "sip:alice@AtLanTa.CoM;Transport=tcp something",
+        ));
+    }
+
+    #[test]
+    fn rfc2() {
+        assert!(equivalent("sip:carol@chicago.com", "sip:carol@chicago.com;newparam=5"));
+        assert!(equivalent("sip:carol@chicago.com", "sip:carol@chicago.com;security=on"));
+        assert!(equivalent(
+            "sip:carol@chicago.com;newparam=5",
+            "sip:carol@chicago.com;security=on"
+        ));
+    }
+
+    #[test]
+    fn rfc3() {
+        assert!(equivalent(
+            "sip:biloxi.com;transport=tcp;method=REGISTER?to=sip:bob%40biloxi.com",
+            "sip:biloxi.com;method=REGISTER;transport=tcp?to=sip:bob%40biloxi.com",
+        ));
+    }
+
+    #[test]
+    fn rfc4() {
+        assert!(equivalent(
+            "sip:alice@atlanta.com?subject=project%20x&priority=urgent",
+            "sip:alice@atlanta.com?priority=urgent&subject=project%20x",
+        ));
+    }
+
+    #[test]
+    fn rfc_not_equivalent() {
+        //different usernames
+        assert!(!equivalent(
+            "SIP:ALICE@AtLanTa.CoM;Transport=udp",
+            "sip:alice@AtLanTa.CoM;Transport=UDP"
+        ));
+        //can resolve to different ports
+        assert!(!equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com:5060"));
+        //can resolve to different transports
+        assert!(!equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com;transport=udp"));
+        //can resolve to different port and transports
+        assert!(!equivalent(
+            "sip:bob@biloxi.com",
+            "sip:bob@biloxi.com:6000;transport=tcp"
+        ));
+        //different header component
+        assert!(!equivalent(
+            "sip:carol@chicago.com",
+            "sip:carol@chicago.com?Subject=next%20meeting"
+        ));
+        //even though that's what phone21.boxesbybob.com resolves to
+        assert!(!equivalent("sip:bob@phone21.boxesbybob.com", "sip:bob@192.0.2.4"));
+        //both have the param, with different values
+        assert!(!equivalent(
+            "sip:carol@chicago.com;security=on",
+            "sip:carol@chicago.com;security=off"
+        ));
+    }
+
+    #[test]
+    fn headers() {
+        assert!(equivalent(
+            "sip:alice@atlanta.com?Subject=project%20x&priority=urgent",
+            "sip:alice@atlanta.com?priority=urgent&subject=project%20x",
+        ));
+        //values are compared after decoding, but case-sensitively
+        assert!(equivalent("sip:alice@atlanta.com?subject=%41", "sip:alice@atlanta.com?subject=A"));
+        assert!(!equivalent("sip:alice@atlanta.com?subject=a", "sip:alice@atlanta.com?subject=A"));
+        //the count of each header matters
+        assert!(!equivalent(
+            "sip:alice@atlanta.com?route=a&route=a",
+            "sip:alice@atlanta.com?route=a"
+        ));
+        assert!(!equivalent(
+            "sip:alice@atlanta.com?route=a&route=a",
+            "sip:alice@atlanta.com?route=a&route=b"
+        ));
+        assert!(equivalent(
+            "sip:alice@atlanta.com?route=a&route=b",
+            "sip:alice@atlanta.com?route=b&route=a"
+        ));
+    }
+
+    #[test]
+    fn strict_params() {
+        assert!(!equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com;user=phone"));
+        assert!(!equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com;ttl=1"));
+        assert!(!equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com;method=INVITE"));
+        assert!(!equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com;maddr=239.255.255.1"));
+        assert!(equivalent("sip:bob@biloxi.com", "sip:bob@biloxi.com;lr"));
+    }
+
+    #[test]
+    fn schemes() {
+        assert!(!equivalent("sip:bob@biloxi.com", "sips:bob@biloxi.com"));
+    }
+
+    #[test]
+    fn ipv6() {
+        assert!(equivalent("sip:[::1]:5060", "sip:[0:0:0:0:0:0:0:1]:5060"));
+    }
+
+    #[test]
+    fn params() {
+        assert!(Param::Maddr(Maddr::new("Example.com"))
+            .equivalent(&Param::Maddr(Maddr::new("example.com"))));
+        assert!(!Param::Maddr(Maddr::new("example.com"))
+            .equivalent(&Param::Ttl(rsip::param::Ttl::new("example.com"))));
+    }
+}
+
+//examples taken from RFC5118 (SIP Torture Test Messages for IPv6)
+mod rfc5118 {
+    use super::*;
+    use rsip::common::uri::{Auth, Host, HostWithPort};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn ipv6_reference() {
+        let uri = Uri::try_from("sip:[2001:db8::10]").unwrap();
+
+        assert_eq!(
+            uri,
+            Uri {
+                scheme: Some(Scheme::Sip),
+                host_with_port: HostWithPort {
+                    host: Host::IpAddr("2001:db8::10".parse().unwrap()),
+                    port: None
+                },
+                ..Default::default()
+            }
+        );
+        assert_eq!(uri.to_string(), "sip:[2001:db8::10]");
+    }
+
+    #[test]
+    fn ipv6_reference_with_port() {
+        let uri = Uri::try_from("sip:[2001:db8::10]:5070").unwrap();
+
+        assert_eq!(uri.host_with_port.port, Some(5070.into()));
+        assert_eq!(uri.to_string(), "sip:[2001:db8::10]:5070");
+    }
+
+    #[test]
+    fn port_looking_ipv6_reference() {
+        //the last group is part of the address, not a port
+        let uri = Uri::try_from("sip:[2001:db8::10:5070]").unwrap();
+
+        assert_eq!(
+            uri.host_with_port,
+            HostWithPort {
+                host: Host::IpAddr("2001:db8::10:5070".parse().unwrap()),
+                port: None
+            }
+        );
+    }
+
+    #[test]
+    fn ipv6_reference_with_user_and_params() {
+        let uri = Uri::try_from("sip:user@[2001:db8::10];transport=tcp").unwrap();
+
+        assert_eq!(uri.auth, Some(Auth::from(("user", Option::<String>::None))));
+        assert_eq!(
+            uri.host_with_port.host,
+            Host::IpAddr("2001:db8::10".parse().unwrap())
+        );
+        assert_eq!(uri.to_string(), "sip:user@[2001:db8::10];transport=TCP");
+    }
+
+    #[test]
+    fn ipv4_mapped_ipv6_reference() {
+        let uri = Uri::try_from("sip:[::ffff:192.0.2.10]").unwrap();
+
+        assert_eq!(
+            uri.host_with_port.host,
+            Host::IpAddr("::ffff:192.0.2.10".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn unterminated_ipv6_reference() {
+        assert!(Uri::try_from("sip:[2001:db8::10").is_err());
+    }
+}
+
+mod uri_headers {
+    use super::*;
+    use rsip::{
+        common::uri::{uri_header, UriHeader},
+        headers, Header,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn parser() {
+        let uri =
+            Uri::try_from("sip:bob@biloxi.com;transport=tcp?Subject=hi%20there&Priority=urgent")
+                .unwrap();
+
+        assert_eq!(uri.params, vec![Param::Transport(rsip::Transport::Tcp)]);
+        assert_eq!(
+            uri.headers,
+            vec![
+                UriHeader::from(("Subject", "hi there")),
+                UriHeader::from(("Priority", "urgent"))
+            ]
+            .into()
+        );
+        assert_eq!(uri.headers.get("subject"), Some("hi there"));
+    }
+
+    #[test]
+    fn needless_escapes() {
+        //the escaped form of a URI header is not kept, needless escapes are dropped
+        let uri = Uri::try_from("sip:bob@biloxi.com?Subject=%68i%20there").unwrap();
+
+        assert_eq!(uri.headers.get("Subject"), Some("hi there"));
+        assert_eq!(uri.to_string(), "sip:bob@biloxi.com?Subject=hi%20there");
+    }
+
+    #[test]
+    fn display() {
+        let uri = Uri {
+            scheme: Some(Scheme::Sip),
+            host_with_port: rsip::Domain::from("biloxi.com").into(),
+            headers: vec![
+                UriHeader::from(("Replaces", "12345@b.com;to-tag=1;from-tag=2")),
+                UriHeader::from(("Subject", "hi")),
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            uri.to_string(),
+            "sip:biloxi.com?Replaces=12345%40b.com%3Bto-tag%3D1%3Bfrom-tag%3D2&Subject=hi"
+        );
+        assert_eq!(Uri::try_from(uri.to_string()).unwrap(), uri);
+    }
+
+    #[test]
+    fn header_fields() {
+        let uri = Uri::try_from("sip:bob@biloxi.com?Subject=hi&Call-ID=abc%40b.com&body=hello")
+            .unwrap();
+
+        assert_eq!(
+            uri.header_fields().unwrap(),
+            vec![
+                Header::Subject(headers::Subject::from("hi")),
+                Header::CallId(headers::CallId::from("abc@b.com")),
+            ]
+            .into()
+        );
+        assert_eq!(uri.body(), Some("hello"));
+        assert_eq!(uri.without_headers().to_string(), "sip:bob@biloxi.com");
+    }
+
+    #[test]
+    fn in_addr_spec() {
+        let to = rsip::typed::To::try_from(rsip::headers::To::from(
+            "<sip:carol@chicago.com?Subject=conference>;tag=1",
+        ))
+        .unwrap();
+
+        assert_eq!(to.uri.headers.get("Subject"), Some("conference"));
+        assert_eq!(
+            to.to_string(),
+            "<sip:carol@chicago.com?Subject=conference>;tag=1"
+        );
+    }
+
+    #[test]
+    fn tokenizer() {
+        assert_eq!(
+            uri_header::Tokenizer::tokenize_all("?a=b&c=%20d something"),
+            Ok((" something", vec![("a", "b").into(), ("c", "%20d").into()])),
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Uri::try_from("sip:bob@biloxi.com?Subject=%2").is_err());
+        assert_eq!(
+            uri_header::Tokenizer::tokenize_all("?=b"),
+            Err(nom::Err::Error(rsip::TokenizerError::from(
+                "failed to tokenize uri headers: ?=b"
+            ))),
+        );
+    }
+}
+
+mod absolute_uri {
+    use super::*;
+    use rsip::{headers::typed, prelude::*, Request};
+    use std::convert::TryFrom;
+
+    fn opaque(scheme: &str, opaque: &str) -> Uri {
+        Uri {
+            scheme: Some(Scheme::Other(scheme.into())),
+            opaque: Some(opaque.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parser() {
+        assert_eq!(
+            Uri::try_from("urn:service:sos"),
+            Ok(opaque("urn", "service:sos"))
+        );
+        assert_eq!(
+            Uri::try_from("mailto:alice@atlanta.com"),
+            Ok(opaque("mailto", "alice@atlanta.com"))
+        );
+        assert_eq!(
+            Uri::try_from("https://www.example.com/alice/photo.jpg?size=1;q=2"),
+            Ok(opaque("https", "//www.example.com/alice/photo.jpg?size=1;q=2"))
+        );
+        assert_eq!(
+            Uri::try_from("pres:alice@atlanta.com"),
+            Ok(opaque("pres", "alice@atlanta.com"))
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(opaque("urn", "service:sos").to_string(), "urn:service:sos");
         assert_eq!(
-            Uri::try_from("sip:%61lice@atlanta.com;transport=TCP something"),
-            Uri::try_from("sip:alice@AtLanTa.CoM;Transport=tcp something"),
+            opaque("https", "//www.example.com/alice").to_string(),
+            "https://www.example.com/alice"
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn without_scheme() {
+        assert!(Uri::try_from("server2.com:5060").unwrap().is_sip());
+        assert_eq!(
+            Uri::try_from("user:password@server2.com:5060").unwrap().auth,
+            Some(("user", Some("password")).into())
+        );
+    }
+
+    #[test]
+    fn is_sip() {
+        assert!(Uri::try_from("sip:alice@atlanta.com").unwrap().is_sip());
+        assert!(Uri::try_from("sips:alice@atlanta.com").unwrap().is_sip());
+        assert!(!Uri::try_from("tel:+12125551212").unwrap().is_sip());
+        assert!(!Uri::try_from("urn:service:sos").unwrap().is_sip());
+        assert!(Uri::try_from("urn:service:sos").unwrap().is_opaque());
+        assert!(!Uri::try_from("sip:alice@atlanta.com").unwrap().is_opaque());
+    }
+
+    #[test]
+    fn request_uri() {
+        let raw = concat!(
+            "INVITE urn:service:sos SIP/2.0\r\n",
+            "Contact: <mailto:alice@atlanta.com>;expires=3600\r\n",
+            "Call-Info: <http://www.example.com/alice/photo.jpg>;purpose=icon\r\n",
+            "\r\n"
+        );
+        let request = Request::try_from(raw).unwrap();
+
+        assert_eq!(request.uri, opaque("urn", "service:sos"));
+        assert_eq!(
+            request.contact_header().unwrap().typed().unwrap().uri,
+            opaque("mailto", "alice@atlanta.com")
+        );
+        assert_eq!(
+            typed::CallInfo::try_from(
+                request
+                    .headers
+                    .iter()
+                    .find_map(|header| match header {
+                        rsip::Header::CallInfo(call_info) => Some(call_info.clone()),
+                        _ => None,
+                    })
+                    .unwrap()
+            )
+            .unwrap()
+            .uris()[0]
+                .uri,
+            opaque("http", "//www.example.com/alice/photo.jpg")
+        );
+        assert_eq!(request.to_string(), raw);
+    }
+}
@@ -35,6 +35,16 @@ mod parser {
         );
     }
 
+    #[test]
+    fn parser3() {
+        let param: Param = Tokenizer::from(("method".as_bytes(), Some("X-Vendor".as_bytes())))
+            .try_into()
+            .unwrap();
+
+        assert_eq!(param, Param::Method(rsip::Method::Other("X-Vendor".into())));
+        assert_eq!(param.to_string(), String::from(";method=X-Vendor"));
+    }
+
     #[test]
     fn other1() {
         assert_eq!(
@@ -74,6 +84,48 @@ mod parser {
             ";param=%[dupa]"
         );
     }
+
+    #[test]
+    fn other6() {
+        let uri = rsip::Uri::try_from("sip:alice@atlanta.com;param=hello%20world%2541").unwrap();
+
+        assert_eq!(
+            uri.params,
+            vec![Param::Other("param".into(), Some("hello world%41".into()))]
+        );
+        assert_eq!(uri.to_string(), "sip:alice@atlanta.com;param=hello%20world%2541");
+    }
+
+    #[test]
+    fn other7() {
+        let mut uri = rsip::Uri::try_from("sip:alice@atlanta.com;param=\"a%20b\"").unwrap();
+        assert_eq!(
+            uri.params,
+            vec![Param::Other("param".into(), Some("\"a%20b\"".into()))]
+        );
+
+        uri.params = vec![Param::Other("param".into(), Some("a b;c".into()))];
+        assert_eq!(uri.to_string(), "sip:alice@atlanta.com;param=a%20b%3Bc");
+    }
+
+    #[test]
+    fn other8() {
+        //`%` is just a token char in header params
+        let param =
+            Param::try_from(Tokenizer::tokenize(";foo=a%41".as_bytes()).unwrap().1).unwrap();
+
+        assert_eq!(param, Param::Other("foo".into(), Some("a%41".into())));
+        assert_eq!(param.to_string(), ";foo=a%41");
+    }
+
+    #[test]
+    fn other9() {
+        //the escaped form of a URI param is not kept, needless escapes are dropped
+        let uri = rsip::Uri::try_from("sip:alice@atlanta.com;param=%61b%20c").unwrap();
+
+        assert_eq!(uri.params, vec![Param::Other("param".into(), Some("ab c".into()))]);
+        assert_eq!(uri.to_string(), "sip:alice@atlanta.com;param=ab%20c");
+    }
 }
 
 mod tokenizer {
@@ -0,0 +1,148 @@
+use rsip::{
+    common::uri::{param::User, Auth, Host, Param, Scheme, TelUri, Uri},
+    Error,
+};
+use std::convert::{TryFrom, TryInto};
+
+mod parser {
+    use super::*;
+
+    #[test]
+    fn global() {
+        assert_eq!(
+            TelUri::try_from("tel:+1-201-555-0123"),
+            Ok(TelUri {
+                number: "+12015550123".into(),
+                phone_context: None,
+                ext: None,
+                isub: None,
+                params: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn global_with_params() {
+        assert_eq!(
+            TelUri::try_from("tel:+1.(201).555.0123;ext=12-34;isub=1411;tsp=gw.example.com"),
+            Ok(TelUri {
+                number: "+12015550123".into(),
+                phone_context: None,
+                ext: Some("1234".into()),
+                isub: Some("1411".into()),
+                params: vec![Param::Other("tsp".into(), Some("gw.example.com".into()))]
+            })
+        );
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            TelUri::try_from("tel:7042;phone-context=example.com"),
+            Ok(TelUri {
+                number: "7042".into(),
+                phone_context: Some("example.com".into()),
+                ext: None,
+                isub: None,
+                params: vec![]
+            })
+        );
+        assert_eq!(
+            TelUri::try_from("tel:863-1234;phone-context=+1-914-555"),
+            Ok(TelUri {
+                number: "8631234".into(),
+                phone_context: Some("+1914555".into()),
+                ext: None,
+                isub: None,
+                params: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn from_sip() {
+        let uri = Uri::try_from("sip:+1-212-555-1212;ext=123@gateway.com;user=phone").unwrap();
+
+        assert_eq!(
+            TelUri::try_from(&uri),
+            Ok(TelUri {
+                number: "+12125551212".into(),
+                phone_context: None,
+                ext: Some("123".into()),
+                isub: None,
+                params: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            TelUri::try_from("tel:7042"),
+            Err(Error::MissingParam("phone-context".into()))
+        );
+        assert_eq!(
+            TelUri::try_from("tel:+1-212-5a5"),
+            Err(Error::ParseError("invalid tel number: +12125a5".into()))
+        );
+        assert!(TelUri::try_from("tel:+1212;ext=12a").is_err());
+        assert!(TelUri::try_from("sip:+1212@gateway.com").is_err());
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            TelUri {
+                number: "8631234".into(),
+                phone_context: Some("+1914555".into()),
+                ext: Some("22".into()),
+                isub: None,
+                params: vec![Param::Other("tsp".into(), Some("a.example.com".into()))]
+            }
+            .to_string(),
+            "tel:8631234;ext=22;phone-context=+1914555;tsp=a.example.com"
+        );
+    }
+
+    #[test]
+    fn into_uri() {
+        let uri: Uri = TelUri::try_from("tel:+1-201-555-0123;ext=1")
+            .unwrap()
+            .into();
+
+        assert_eq!(uri.scheme, Some(Scheme::Tel));
+        assert_eq!(uri.to_string(), "tel:+12015550123;ext=1");
+        assert_eq!(uri.try_into(), TelUri::try_from("tel:+12015550123;ext=1"));
+    }
+
+    #[test]
+    fn to_sip_uri() {
+        let sip_uri = TelUri::try_from("tel:+358-555-1234567;postd=pp22")
+            .unwrap()
+            .to_sip_uri(Host::from("foo.com"));
+
+        assert_eq!(
+            sip_uri,
+            Uri {
+                scheme: Some(Scheme::Sip),
+                auth: Some(Auth::from(("+3585551234567;postd=pp22", Option::<String>::None))),
+                host_with_port: Host::from("foo.com").into(),
+                params: vec![Param::User(User::new("phone"))],
+                headers: Default::default(),
+                opaque: None
+            }
+        );
+        assert_eq!(
+            sip_uri.to_string(),
+            "sip:+3585551234567;postd=pp22@foo.com;user=phone"
+        );
+        assert_eq!(
+            TelUri::try_from(&sip_uri),
+            TelUri::try_from("tel:+358-555-1234567;postd=pp22")
+        );
+    }
+}
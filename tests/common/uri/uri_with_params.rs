@@ -18,7 +18,8 @@ mod display {
                     auth: None,
                     host_with_port: ("client.biloxi.example.com", Some(5061)).into(),
                     params: vec![Param::Other("s".into(), Some("2".into())),],
-                    headers: Default::default()
+                    headers: Default::default(),
+                    opaque: None
                 },
                 params: vec![
                     Param::Maddr(Maddr::new("255.255.255.0")),
@@ -42,7 +43,8 @@ mod display {
                     auth: None,
                     host_with_port: ("client.biloxi.example.com", Some(5061)).into(),
                     params: vec![Param::Other("s".into(), Some("2".into())),],
-                    headers: Default::default()
+                    headers: Default::default(),
+                    opaque: None
                 },
                 params: vec![],
             }
@@ -61,7 +63,8 @@ mod display {
                     auth: None,
                     host_with_port: "+12124567890".try_into().unwrap(),
                     params: Default::default(),
-                    headers: Default::default()
+                    headers: Default::default(),
+                    opaque: None
                 },
                 params: vec![Param::Tag(tag.clone())],
             }
@@ -104,7 +107,8 @@ mod parser {
                     auth: None,
                     host_with_port: ("client.biloxi.example.com", Some(5061)).into(),
                     params: vec![Param::Other("s".into(), Some("2".into())),],
-                    headers: Default::default()
+                    headers: Default::default(),
+                    opaque: None
                 },
                 params: vec![
                     Param::Maddr(Maddr::new("255.255.255.0")),
@@ -141,7 +145,8 @@ mod parser {
                     auth: None,
                     host_with_port: ("client.biloxi.example.com", Some(5061)).into(),
                     params: vec![Param::Other("s".into(), Some("2".into())),],
-                    headers: Default::default()
+                    headers: Default::default(),
+                    opaque: None
                 },
                 params: vec![
                     Param::Maddr(Maddr::new("255.255.255.0")),
@@ -19,7 +19,8 @@ mod display {
                         auth: None,
                         host_with_port: ("client.biloxi.example.com", Some(5061)).into(),
                         params: vec![Param::Other("s".into(), Some("2".into())),],
-                        headers: Default::default()
+                        headers: Default::default(),
+                        opaque: None
                     },
                     params: vec![
                         Param::Maddr(Maddr::new("255.255.255.0")),
@@ -33,7 +34,8 @@ mod display {
                         auth: None,
                         host_with_port: ("www.example.com", Option::<u16>::None).into(),
                         params: vec![Param::Other("foo".into(), Some("bar".into())),],
-                        headers: Default::default()
+                        headers: Default::default(),
+                        opaque: None
                     },
                     params: vec![Param::Other("test".into(), None)],
                 }
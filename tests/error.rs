@@ -0,0 +1,125 @@
+use rsip::{Error, ErrorKind, ErrorLocation, Request, Response, SipMessage};
+use std::convert::TryFrom;
+
+mod location {
+    use super::*;
+
+    #[test]
+    fn header_line() {
+        let req = concat!(
+            "REGISTER sips:ss2.biloxi.example.com SIP/2.0\r\n",
+            "Via: SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashd92\r\n",
+            "Authorization Digest username=\"bob\", realm=\"atlanta.example.com\"\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+        let error = Request::try_from(req).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Tokenize);
+        assert_eq!(
+            error.location(),
+            Some(&ErrorLocation {
+                offset: 117,
+                line: 3,
+                column: 1,
+                header: Some("Authorization".into()),
+                expected: Some("headers".into()),
+                snippet: "Authorization Digest username=\"bob\", rea".into()
+            })
+        );
+    }
+
+    #[test]
+    fn start_line() {
+        let error = SipMessage::try_from(concat!(
+            "INVITE  sip:bob@biloxi.com SIP/2.0\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ))
+        .unwrap_err();
+
+        let location = error.location().expect("location");
+        assert_eq!((location.line, location.column), (1, 8));
+        assert_eq!(location.header, None);
+        assert_eq!(location.snippet, " sip:bob@biloxi.com SIP/2.0");
+    }
+
+    #[test]
+    fn bytes_and_str_agree() {
+        let res = concat!(
+            "SIP/2.0 200 OK\r\n",
+            "Max-Forwards: 70\r\n",
+            "Call-ID 1j9FpLxk3uxtm8tn@biloxi.example.com\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+
+        let from_str = Response::try_from(res).unwrap_err();
+        let from_bytes = Response::try_from(res.as_bytes().to_vec()).unwrap_err();
+
+        assert_eq!(from_str, from_bytes);
+        assert_eq!(
+            from_str
+                .location()
+                .map(|location| (location.line, location.column)),
+            Some((3, 1))
+        );
+    }
+
+    #[test]
+    fn display() {
+        let location = ErrorLocation::new(
+            b"INVITE sip:bob@biloxi.com SIP/2.0\r\nVia: SIP/2.0/UDP\r\n foo bar\r\n\r\n",
+            54,
+            Some("via".into()),
+        )
+        .expect("location");
+
+        assert_eq!(location.header, Some("Via".into()));
+        assert_eq!(
+            location.to_string(),
+            "line 3, column 2 (header Via), expected via, near `foo bar`"
+        );
+    }
+
+    #[test]
+    fn out_of_input() {
+        assert_eq!(ErrorLocation::new(b"INVITE", 10, None), None);
+    }
+}
+
+mod kind {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            Error::missing_header("Via").kind(),
+            ErrorKind::MissingHeader
+        );
+        assert_eq!(Error::missing_param("tag").kind(), ErrorKind::MissingParam);
+        assert_eq!(
+            Error::InvalidParam("foo".into()).kind(),
+            ErrorKind::InvalidParam
+        );
+        assert_eq!(Error::ParseError("foo".into()).kind(), ErrorKind::Parse);
+        assert_eq!(Error::tokenizer(("foo", "bar")).kind(), ErrorKind::Tokenize);
+        assert_eq!(Error::Utf8Error("foo".into()).kind(), ErrorKind::Utf8);
+        assert_eq!(
+            Error::LimitExceeded(rsip::message::Limit::Headers, 10).kind(),
+            ErrorKind::LimitExceeded
+        );
+        assert_eq!(
+            Error::Unexpected("foo".into()).kind(),
+            ErrorKind::Unexpected
+        );
+    }
+
+    #[test]
+    fn without_location() {
+        let error = Error::tokenizer(("foo", "bar"));
+
+        assert_eq!(error.location(), None);
+        assert_eq!(
+            error.to_string(),
+            "Tokenizer error: failed to tokenize foo: bar"
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::AllowEvents;
+
+validate_untyped_header_trait!(AllowEvents);
+validate_to_typed_header_trait!(AllowEvents);
@@ -0,0 +1,51 @@
+use rsip::headers::typed::{allow_events::Tokenizer, AllowEvents};
+use std::convert::TryInto;
+
+validate_typed_header_trait!(AllowEvents);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            AllowEvents(vec!["presence".into(), "dialog".into()]).to_string(),
+            String::from("presence, dialog")
+        );
+    }
+}
+
+mod try_from_tokenizer {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        let allow_events: AllowEvents = Tokenizer {
+            tokens: vec!["presence", "presence.winfo", "dialog"],
+        }
+        .try_into()?;
+
+        assert_eq!(
+            allow_events,
+            AllowEvents(vec![
+                "presence".into(),
+                "presence.winfo".into(),
+                "dialog".into()
+            ])
+        );
+        assert!(allow_events.allows("Dialog"));
+        assert!(!allow_events.allows("message-summary"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() {
+        let allow_events: Result<AllowEvents, rsip::Error> = Tokenizer {
+            tokens: vec!["presence", ""],
+        }
+        .try_into();
+
+        assert!(allow_events.is_err());
+    }
+}
@@ -18,13 +18,11 @@ mod display {
                     display_name: Some("Alice".into()),
                     uri: uri::Uri {
                         scheme: Some(uri::Scheme::Sip),
-                        auth: Some(uri::Auth {
-                            user: "alice".into(),
-                            password: None
-                        }),
+                        auth: Some(uri::Auth::from(("alice", Option::<String>::None))),
                         host_with_port: uri::HostWithPort::try_from("atlanta.example.com")?,
                         params: vec![],
-                        headers: vec![].into()
+                        headers: vec![].into(),
+                        opaque: None
                     },
                     params: vec![uri::Param::Tag(uri::param::Tag::new("9fxced76sl"))]
                 }
@@ -59,13 +57,11 @@ mod try_from_tokenizer {
                 display_name: Some("Alice".into()),
                 uri: uri::Uri {
                     scheme: Some(uri::Scheme::Sip),
-                    auth: Some(uri::Auth {
-                        user: "alice".into(),
-                        password: None
-                    }),
+                    auth: Some(uri::Auth::from(("alice", Option::<String>::None))),
                     host_with_port: uri::HostWithPort::try_from("atlanta.example.com")?,
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 params: vec![uri::Param::Tag(uri::param::Tag::new("9fxced76sl"))]
             })
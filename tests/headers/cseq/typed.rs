@@ -23,4 +23,35 @@ mod try_from_tokenizer {
             })
         );
     }
+
+    #[test]
+    fn try_from2() {
+        assert_eq!(
+            Tokenizer {
+                seq: "1",
+                method: "X-Vendor"
+            }
+            .try_into(),
+            Ok(CSeq {
+                seq: 1,
+                method: Method::Other("X-Vendor".into())
+            })
+        );
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            CSeq {
+                seq: 1,
+                method: Method::Other("X-Vendor".into())
+            }
+            .to_string(),
+            String::from("1 X-Vendor")
+        );
+    }
 }
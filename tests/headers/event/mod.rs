@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::Event;
+
+validate_untyped_header_trait!(Event);
+validate_to_typed_header_trait!(Event);
@@ -0,0 +1,126 @@
+use rsip::{
+    common::uri::{self, Param},
+    headers::{
+        typed::{event::Tokenizer, Event, Tokenize},
+        untyped::ToTypedHeader,
+        UntypedHeader,
+    },
+};
+use std::convert::TryInto;
+
+validate_typed_header_trait!(Event);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            Event::new("dialog").with_id("xyz").to_string(),
+            String::from("dialog;id=xyz")
+        );
+    }
+
+    #[test]
+    fn display2() {
+        assert_eq!(
+            Event {
+                package: "presence".into(),
+                templates: vec!["winfo".into()],
+                id: None,
+                params: vec![Param::Other(
+                    "call-id".into(),
+                    Some("a84b4c76e66710".into())
+                )]
+            }
+            .to_string(),
+            String::from("presence.winfo;call-id=a84b4c76e66710")
+        );
+    }
+}
+
+mod try_from_tokenizer {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        assert_eq!(
+            Tokenizer {
+                value: "dialog",
+                params: vec![uri::param::Tokenizer::from(("id", Some("xyz")))]
+            }
+            .try_into(),
+            Ok(Event::new("dialog").with_id("xyz"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() -> Result<(), rsip::Error> {
+        let event: Event = Tokenize::tokenize("presence.winfo ; ID=1; call-id=\"a@b\"")
+            .and_then(|tokenizer: Tokenizer| tokenizer.try_into())?;
+
+        assert_eq!(
+            event,
+            Event {
+                package: "presence".into(),
+                templates: vec!["winfo".into()],
+                id: Some("1".into()),
+                params: vec![Param::Other("call-id".into(), Some("\"a@b\"".into()))]
+            }
+        );
+        assert_eq!(event.event_type(), "presence.winfo");
+        assert!(event.is("Presence.Winfo"));
+        assert!(!event.is("presence"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_3() {
+        assert!(rsip::headers::Event::new("").typed().is_err());
+    }
+
+    #[test]
+    fn try_from_4() -> Result<(), rsip::Error> {
+        let event = rsip::headers::Event::new("dialog;sla;include-session-description").typed()?;
+
+        assert_eq!(
+            event,
+            Event {
+                params: vec![
+                    Param::Other("sla".into(), None),
+                    Param::Other("include-session-description".into(), None)
+                ],
+                ..Event::new("dialog")
+            }
+        );
+        assert_eq!(event.to_string(), "dialog;sla;include-session-description");
+
+        let event = rsip::headers::Event::new("dialog;a;b=1;id=2").typed()?;
+        assert_eq!(
+            event,
+            Event {
+                id: Some("2".into()),
+                params: vec![
+                    Param::Other("a".into(), None),
+                    Param::Other("b".into(), Some("1".into()))
+                ],
+                ..Event::new("dialog")
+            }
+        );
+
+        Ok(())
+    }
+}
+
+#[test]
+fn untyped() -> Result<(), rsip::Error> {
+    let event = rsip::headers::Event::new("dialog;id=xyz").typed()?;
+
+    assert_eq!(event, Event::new("dialog").with_id("xyz"));
+    assert_eq!(event.untyped().value(), "dialog;id=xyz");
+
+    Ok(())
+}
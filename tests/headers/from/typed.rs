@@ -18,13 +18,11 @@ mod display {
                     display_name: Some("Alice".into()),
                     uri: uri::Uri {
                         scheme: Some(uri::Scheme::Sip),
-                        auth: Some(uri::Auth {
-                            user: "alice".into(),
-                            password: None
-                        }),
+                        auth: Some(uri::Auth::from(("alice", Option::<String>::None))),
                         host_with_port: uri::HostWithPort::try_from("atlanta.example.com")?,
                         params: vec![],
-                        headers: vec![].into()
+                        headers: vec![].into(),
+                        opaque: None
                     },
                     params: vec![uri::Param::Tag(uri::param::Tag::new("9fxced76sl"))]
                 }
@@ -59,13 +57,11 @@ mod try_from_tokenizer {
                 display_name: Some("Alice".into()),
                 uri: uri::Uri {
                     scheme: Some(uri::Scheme::Sip),
-                    auth: Some(uri::Auth {
-                        user: "alice".into(),
-                        password: None
-                    }),
+                    auth: Some(uri::Auth::from(("alice", Option::<String>::None))),
                     host_with_port: uri::HostWithPort::try_from("atlanta.example.com")?,
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 params: vec![uri::Param::Tag(uri::param::Tag::new("9fxced76sl"))]
             })
@@ -74,3 +70,21 @@ mod try_from_tokenizer {
         Ok(())
     }
 }
+
+mod non_token_display_name {
+    use rsip::headers::{untyped::ToTypedHeader, UntypedHeader};
+
+    //RFC3261 only allows tokens in an unquoted display name, yet real-world traffic breaks it
+    #[test]
+    fn typed() -> Result<(), rsip::Error> {
+        let header =
+            rsip::headers::From::new("alice@example.com <sip:alice@example.com>;tag=1").typed()?;
+        assert_eq!(header.display_name, Some("alice@example.com".into()));
+        assert_eq!(header.uri.to_string(), "sip:alice@example.com");
+
+        let header = rsip::headers::From::new("Jürgen <sip:j@example.com>;tag=1").typed()?;
+        assert_eq!(header.display_name, Some("Jürgen".into()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::MinSe;
+
+validate_untyped_header_trait!(MinSe);
+validate_to_typed_header_trait!(MinSe);
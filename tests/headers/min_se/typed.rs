@@ -0,0 +1,20 @@
+use rsip::headers::{typed::MinSe, untyped::ToTypedHeader, Header, UntypedHeader};
+
+validate_typed_header_trait!(MinSe);
+
+#[test]
+fn display() {
+    assert_eq!(MinSe::new(90).to_string(), String::from("90"));
+    assert_eq!(
+        Header::from(MinSe::new(90)).to_string(),
+        String::from("Min-SE: 90")
+    );
+}
+
+#[test]
+fn try_from_untyped() -> Result<(), rsip::Error> {
+    assert_eq!(rsip::headers::MinSe::new(" 120 ").typed()?, MinSe::new(120));
+    assert!(rsip::headers::MinSe::new("-1").typed().is_err());
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use rsip::headers::UntypedHeader;
 pub mod accept;
 pub mod alert_info;
 pub mod allow;
+pub mod allow_events;
 pub mod auth;
 pub mod authentication_info;
 pub mod authorization;
@@ -13,14 +14,28 @@ pub mod content_length;
 pub mod content_type;
 pub mod cseq;
 pub mod error_info;
+pub mod event;
 pub mod from;
 pub mod in_reply_to;
 pub mod max_forwards;
 pub mod media_type;
+pub mod min_se;
+pub mod p_asserted_identity;
+pub mod p_preferred_identity;
 pub mod priority;
+pub mod privacy;
 pub mod proxy_authenticate;
+pub mod rack;
 pub mod record_route;
+pub mod refer_to;
+pub mod referred_by;
+pub mod registry;
+pub mod replaces;
 pub mod reply_to;
+pub mod rseq;
+pub mod session_expires;
+pub mod subscription_state;
+pub mod target_dialog;
 pub mod to;
 pub mod tokenizers;
 pub mod via;
@@ -55,9 +70,9 @@ mod tokenizer {
     #[test]
     fn tokenizer1() {
         assert_eq!(
-            Tokenizer::tokenize(b"Accept: REGISTER, INVITE\r\n something"),
+            Tokenizer::tokenize(b"Accept: REGISTER, INVITE\r\nsomething"),
             Ok((
-                " something".as_bytes(),
+                "something".as_bytes(),
                 Tokenizer {
                     name: "Accept".as_bytes(),
                     value: "REGISTER, INVITE".as_bytes()
@@ -65,4 +80,268 @@ mod tokenizer {
             )),
         );
     }
+
+    #[test]
+    fn tokenizer2() {
+        assert_eq!(
+            Tokenizer::tokenize(b"Accept: REGISTER,\r\n INVITE\r\nsomething"),
+            Ok((
+                "something".as_bytes(),
+                Tokenizer {
+                    name: "Accept".as_bytes(),
+                    value: "REGISTER,\r\n INVITE".as_bytes()
+                }
+            )),
+        );
+    }
+}
+
+mod folding {
+    use super::*;
+    use rsip::headers::Via;
+    use std::convert::TryInto;
+
+    #[test]
+    fn parser1() -> Result<(), rsip::Error> {
+        let header: Header = Tokenizer::tokenize(
+            b"Via: SIP/2.0/UDP pc33.atlanta.com\r\n\t;branch=z9hG4bK776asdhds\r\n",
+        )?
+        .1
+        .try_into()?;
+        assert_eq!(
+            header,
+            Header::Via(Via::new("SIP/2.0/UDP pc33.atlanta.com ;branch=z9hG4bK776asdhds"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parser2() -> Result<(), rsip::Error> {
+        let header: Header = Tokenizer::tokenize(b"Accept:\r\n   REGISTER,  \r\n  INVITE")?
+            .1
+            .try_into()?;
+        assert_eq!(header, Header::Accept(Accept::new("REGISTER, INVITE")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parser3() -> Result<(), rsip::Error> {
+        let header: Header = Tokenizer::tokenize(b"e: gzip,\r\n tar\r\n")?
+            .1
+            .try_into()?;
+        assert_eq!(
+            header,
+            Header::ContentEncoding(rsip::headers::ContentEncoding::new("gzip, tar"))
+        );
+
+        Ok(())
+    }
+}
+
+mod non_utf8 {
+    use super::*;
+    use std::convert::TryInto;
+
+    #[test]
+    fn parser() -> Result<(), rsip::Error> {
+        let header: Header = Tokenizer::tokenize(b"From: \"Jos\xe9\" <sip:jose@example.com>\r\n")?
+            .1
+            .try_into()?;
+        assert_eq!(
+            header,
+            Header::NonUtf8(
+                "From".into(),
+                b"\"Jos\xe9\" <sip:jose@example.com>".to_vec()
+            )
+        );
+        assert_eq!(
+            header.to_string(),
+            "From: \"Jos\u{fffd}\" <sip:jose@example.com>"
+        );
+
+        Ok(())
+    }
+}
+
+mod compact {
+    use super::*;
+    use rsip::headers::{AllowEvents, CallId, ContentLength, From, MaxForwards, Via};
+    use std::convert::TryInto;
+
+    #[test]
+    fn parser() -> Result<(), rsip::Error> {
+        let header: Header = Tokenizer::tokenize(b"i: 1j9FpLxk3uxtm8tn@biloxi.example.com\r\n")?
+            .1
+            .try_into()?;
+        assert_eq!(
+            header,
+            Header::CallId(CallId::new("1j9FpLxk3uxtm8tn@biloxi.example.com"))
+        );
+
+        let header: Header = Tokenizer::tokenize(b"V: SIP/2.0/UDP pc33.atlanta.com\r\n")?
+            .1
+            .try_into()?;
+        assert_eq!(header, Header::Via(Via::new("SIP/2.0/UDP pc33.atlanta.com")));
+
+        let header: Header = Tokenizer::tokenize(b"u: presence, dialog\r\n")?
+            .1
+            .try_into()?;
+        assert_eq!(header, Header::AllowEvents(AllowEvents::new("presence, dialog")));
+        assert_eq!(header.split().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Header::From(From::new("<sip:alice@atlanta.com>;tag=1928301774")).to_compact_string(),
+            String::from("f: <sip:alice@atlanta.com>;tag=1928301774")
+        );
+        assert_eq!(
+            Header::Accept(Accept::new("application/sdp")).to_compact_string(),
+            String::from("Accept: application/sdp")
+        );
+        assert_eq!(Header::ContentLength(ContentLength::new("0")).compact_name(), Some("l"));
+        assert_eq!(Header::MaxForwards(MaxForwards::new("70")).compact_name(), None);
+    }
+}
+
+mod multi_value {
+    use super::*;
+    use rsip::{
+        headers::{Allow, Contact, Headers, Route, Subject, Via, Warning},
+        prelude::*,
+        Request,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            Header::Via(Via::new("SIP/2.0/UDP a.com;branch=1 , SIP/2.0/TCP b.com;branch=2")).split(),
+            vec![
+                Header::Via(Via::new("SIP/2.0/UDP a.com;branch=1")),
+                Header::Via(Via::new("SIP/2.0/TCP b.com;branch=2"))
+            ]
+        );
+        assert_eq!(
+            Header::Allow(Allow::new("INVITE,ACK, BYE")).split(),
+            vec![
+                Header::Allow(Allow::new("INVITE")),
+                Header::Allow(Allow::new("ACK")),
+                Header::Allow(Allow::new("BYE"))
+            ]
+        );
+    }
+
+    #[test]
+    fn split_quoted() {
+        assert_eq!(
+            Header::Contact(Contact::new(
+                "\"Watson, Tom\" <sip:tom@a.com;x=\"a,b\">;q=0.7, <sip:b@b.com?Subject=a,b>"
+            ))
+            .split(),
+            vec![
+                Header::Contact(Contact::new(
+                    "\"Watson, Tom\" <sip:tom@a.com;x=\"a,b\">;q=0.7"
+                )),
+                Header::Contact(Contact::new("<sip:b@b.com?Subject=a,b>"))
+            ]
+        );
+        assert_eq!(
+            Header::Warning(Warning::new(
+                "370 devnull \"Choose a bigger pipe, or \\\"else\\\", really\", 307 isi.edu \"Session parameter 'foo' not understood\""
+            ))
+            .split()
+            .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn single_value() {
+        let subject = Header::Subject(Subject::new("Need more boxes, now"));
+
+        assert!(!subject.is_multi_value());
+        assert_eq!(subject.split(), vec![subject.clone()]);
+        assert!(Header::Route(Route::new("<sip:a.com>")).is_multi_value());
+    }
+
+    #[test]
+    fn normalize_and_combine() {
+        let mut headers: Headers = vec![
+            Header::Via(Via::new("SIP/2.0/UDP a.com, SIP/2.0/UDP b.com")),
+            Header::Subject(Subject::new("hi, there")),
+            Header::Via(Via::new("SIP/2.0/UDP c.com")),
+        ]
+        .into();
+
+        headers.normalize();
+        assert_eq!(
+            headers,
+            vec![
+                Header::Via(Via::new("SIP/2.0/UDP a.com")),
+                Header::Via(Via::new("SIP/2.0/UDP b.com")),
+                Header::Subject(Subject::new("hi, there")),
+                Header::Via(Via::new("SIP/2.0/UDP c.com")),
+            ]
+            .into()
+        );
+
+        headers.combine();
+        assert_eq!(
+            headers,
+            vec![
+                Header::Via(Via::new(
+                    "SIP/2.0/UDP a.com, SIP/2.0/UDP b.com, SIP/2.0/UDP c.com"
+                )),
+                Header::Subject(Subject::new("hi, there")),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn headers_ext() {
+        let request = Request::try_from(concat!(
+            "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP a.com;branch=1, SIP/2.0/UDP b.com;branch=2\r\n",
+            "Via: SIP/2.0/UDP c.com;branch=3\r\n",
+            "Route: <sip:p1.com;lr>, <sip:p2.com;lr>\r\n",
+            "Record-Route: <sip:p3.com;lr>\r\n",
+            "Contact: <sip:alice@a.com>, <sip:alice@b.com>\r\n",
+            "\r\n"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            request.via_values(),
+            vec![
+                Via::new("SIP/2.0/UDP a.com;branch=1"),
+                Via::new("SIP/2.0/UDP b.com;branch=2"),
+                Via::new("SIP/2.0/UDP c.com;branch=3"),
+            ]
+        );
+        assert_eq!(
+            request.via_values()[1].typed().unwrap().branch().unwrap().to_string(),
+            "2"
+        );
+        assert_eq!(request.route_values().len(), 2);
+        assert_eq!(request.record_route_values().len(), 1);
+        assert_eq!(
+            request
+                .headers()
+                .iter()
+                .filter(|header| matches!(header, Header::Route(_)))
+                .count(),
+            1
+        );
+        assert_eq!(
+            request.contact_values()[1].typed().unwrap().uri.to_string(),
+            "sip:alice@b.com"
+        );
+    }
 }
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::PAssertedIdentity;
+
+validate_untyped_header_trait!(PAssertedIdentity);
+validate_to_typed_header_trait!(PAssertedIdentity);
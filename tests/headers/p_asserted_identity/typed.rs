@@ -0,0 +1,81 @@
+use rsip::{
+    common::uri::Uri,
+    headers::{
+        typed::{p_asserted_identity::Tokenizer, Identity, PAssertedIdentity, Tokenize},
+        untyped::ToTypedHeader,
+        Header, UntypedHeader,
+    },
+};
+use std::convert::{TryFrom, TryInto};
+
+validate_typed_header_trait!(PAssertedIdentity);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() -> Result<(), rsip::Error> {
+        assert_eq!(
+            PAssertedIdentity(vec![
+                Identity {
+                    display_name: Some("\"Cullen Jennings\"".into()),
+                    ..Identity::from(Uri::try_from("sip:fluffy@cisco.com")?)
+                },
+                Identity::from(Uri::try_from("tel:+14085264000")?)
+            ])
+            .to_string(),
+            String::from("\"Cullen Jennings\" <sip:fluffy@cisco.com>, <tel:+14085264000>")
+        );
+
+        Ok(())
+    }
+}
+
+mod try_from_tokenizer {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        let identity: PAssertedIdentity =
+            Tokenizer::tokenize("\"Cullen Jennings\" <sip:fluffy@cisco.com>, tel:+14085264000")?
+                .try_into()?;
+
+        assert_eq!(identity.identities().len(), 2);
+        assert_eq!(
+            identity.identities()[0].display_name,
+            Some("\"Cullen Jennings\"".into())
+        );
+        assert_eq!(
+            identity.identities()[0].uri.to_string(),
+            "sip:fluffy@cisco.com"
+        );
+        assert_eq!(identity.identities()[1].uri.to_string(), "tel:+14085264000");
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() {
+        assert!(rsip::headers::PAssertedIdentity::new("").typed().is_err());
+        assert!(rsip::headers::PAssertedIdentity::new("<sip:a@b.com")
+            .typed()
+            .is_err());
+    }
+}
+
+#[test]
+fn multi_value() {
+    let header = Header::PAssertedIdentity(rsip::headers::PAssertedIdentity::new(
+        "\"Jennings, Cullen\" <sip:fluffy@cisco.com>, <tel:+14085264000>",
+    ));
+
+    assert_eq!(
+        header.split(),
+        vec![
+            Header::PAssertedIdentity(rsip::headers::PAssertedIdentity::new(
+                "\"Jennings, Cullen\" <sip:fluffy@cisco.com>"
+            )),
+            Header::PAssertedIdentity(rsip::headers::PAssertedIdentity::new("<tel:+14085264000>"))
+        ]
+    );
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::PPreferredIdentity;
+
+validate_untyped_header_trait!(PPreferredIdentity);
+validate_to_typed_header_trait!(PPreferredIdentity);
@@ -0,0 +1,18 @@
+use rsip::headers::{typed::PPreferredIdentity, untyped::ToTypedHeader, UntypedHeader};
+
+validate_typed_header_trait!(PPreferredIdentity);
+
+#[test]
+fn try_from_untyped() -> Result<(), rsip::Error> {
+    let identity =
+        rsip::headers::PPreferredIdentity::new("\"Cullen Jennings\" <sip:fluffy@cisco.com>")
+            .typed()?;
+
+    assert_eq!(identity.identities().len(), 1);
+    assert_eq!(
+        identity.to_string(),
+        String::from("\"Cullen Jennings\" <sip:fluffy@cisco.com>")
+    );
+
+    Ok(())
+}
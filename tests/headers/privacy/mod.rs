@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::Privacy;
+
+validate_untyped_header_trait!(Privacy);
+validate_to_typed_header_trait!(Privacy);
@@ -0,0 +1,35 @@
+use rsip::headers::{
+    typed::{privacy::PrivacyValue, Privacy},
+    untyped::ToTypedHeader,
+    UntypedHeader,
+};
+
+validate_typed_header_trait!(Privacy);
+
+#[test]
+fn display() {
+    assert_eq!(
+        Privacy(vec![PrivacyValue::Id, PrivacyValue::Critical]).to_string(),
+        String::from("id;critical")
+    );
+}
+
+#[test]
+fn try_from_untyped() -> Result<(), rsip::Error> {
+    let privacy = rsip::headers::Privacy::new("header ; ID;x-foo").typed()?;
+
+    assert_eq!(
+        privacy,
+        Privacy(vec![
+            PrivacyValue::Header,
+            PrivacyValue::Id,
+            PrivacyValue::Other("x-foo".into())
+        ])
+    );
+    assert!(privacy.contains(&PrivacyValue::Id));
+    assert!(privacy.is_requested());
+    assert!(!rsip::headers::Privacy::new("none").typed()?.is_requested());
+    assert!(rsip::headers::Privacy::new("id;;user").typed().is_err());
+
+    Ok(())
+}
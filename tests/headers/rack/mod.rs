@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::RAck;
+
+validate_untyped_header_trait!(RAck);
+validate_to_typed_header_trait!(RAck);
@@ -0,0 +1,32 @@
+use rsip::{
+    headers::{typed::RAck, untyped::ToTypedHeader, Header, UntypedHeader},
+    Method,
+};
+
+validate_typed_header_trait!(RAck);
+
+#[test]
+fn display() {
+    assert_eq!(
+        Header::from(RAck::from((776656, 1, Method::Invite))).to_string(),
+        String::from("RAck: 776656 1 INVITE")
+    );
+}
+
+#[test]
+fn try_from_untyped() -> Result<(), rsip::Error> {
+    assert_eq!(
+        rsip::headers::RAck::new("776656  1 INVITE").typed()?,
+        RAck {
+            rseq: 776656,
+            cseq: 1,
+            method: Method::Invite
+        }
+    );
+    assert!(rsip::headers::RAck::new("776656 1").typed().is_err());
+    assert!(rsip::headers::RAck::new("776656 one INVITE")
+        .typed()
+        .is_err());
+
+    Ok(())
+}
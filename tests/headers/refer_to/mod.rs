@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::ReferTo;
+
+validate_untyped_header_trait!(ReferTo);
+validate_to_typed_header_trait!(ReferTo);
@@ -0,0 +1,120 @@
+use rsip::{
+    common::uri::{self, param::Tag},
+    headers::{
+        typed::{ReferTo, Replaces},
+        untyped::ToTypedHeader,
+        UntypedHeader,
+    },
+    message::HeadersExt,
+    Header, Request,
+};
+use std::convert::{TryFrom, TryInto};
+
+validate_typed_header_trait!(ReferTo);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() -> Result<(), rsip::Error> {
+        let uri: uri::Uri = "sip:carol@chicago.example.com".try_into()?;
+
+        assert_eq!(
+            ReferTo::from(uri.clone()).to_string(),
+            String::from("<sip:carol@chicago.example.com>")
+        );
+        assert_eq!(
+            ReferTo {
+                display_name: Some("Carol".into()),
+                ..ReferTo::from(uri)
+            }
+            .to_string(),
+            String::from("Carol <sip:carol@chicago.example.com>")
+        );
+
+        Ok(())
+    }
+}
+
+mod try_from_untyped {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        let refer_to = rsip::headers::ReferTo::new("<sips:carol@chicago.example.com>").typed()?;
+
+        assert_eq!(refer_to.display_name, None);
+        assert_eq!(refer_to.uri.to_string(), "sips:carol@chicago.example.com");
+        assert_eq!(refer_to.replaces()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_uri_headers() -> Result<(), rsip::Error> {
+        let refer_to = rsip::headers::ReferTo::new(concat!(
+            "<sips:dave@denver.example.org?Replaces=12345%40192.168.118.3%3B",
+            "to-tag%3D12345%3Bfrom-tag%3D5FFE-3994>"
+        ))
+        .typed()?;
+
+        assert_eq!(
+            refer_to.uri.without_headers().to_string(),
+            "sips:dave@denver.example.org"
+        );
+        assert_eq!(
+            refer_to.replaces()?,
+            Some(Replaces::new(
+                "12345@192.168.118.3",
+                Tag::new("12345"),
+                Tag::new("5FFE-3994")
+            ))
+        );
+        assert!(refer_to.uri.header_fields()?.iter().any(
+            |header| matches!(header, Header::Replaces(replaces) if replaces.typed().is_ok())
+        ));
+
+        Ok(())
+    }
+}
+
+#[test]
+fn refer_request() -> Result<(), rsip::Error> {
+    let request = Request::try_from(concat!(
+        "REFER sips:a8342043f@atlanta.example.com;gr SIP/2.0\r\n",
+        "Via: SIP/2.0/TLS 192.0.2.4;branch=z9hG4bKnashds8\r\n",
+        "Max-Forwards: 70\r\n",
+        "To: <sips:a8342043f@atlanta.example.com;gr>\r\n",
+        "From: <sips:b8xxx@biloxi.example.com>;tag=193402342\r\n",
+        "Call-ID: 898234234@agb.example.com\r\n",
+        "CSeq: 93809823 REFER\r\n",
+        "r: <sips:carol@cleveland.example.org?Replaces=a%40b%3Bto-tag%3D1%3Bfrom-tag%3D2>\r\n",
+        "b: <sips:bob@biloxi.example.com>;cid=\"20398823.2UWQFN309shb3@biloxi.example.com\"\r\n",
+        "Target-Dialog: 090459243588173445;local-tag=7553452;remote-tag=31431\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))?;
+
+    let refer_to = request
+        .refer_to_header()
+        .expect("Refer-To header")
+        .typed()?;
+    assert_eq!(
+        refer_to.replaces()?.map(|replaces| replaces.call_id),
+        Some("a@b".into())
+    );
+
+    let referred_by = request
+        .referred_by_header()
+        .expect("Referred-By header")
+        .typed()?;
+    assert_eq!(referred_by.uri.to_string(), "sips:bob@biloxi.example.com");
+
+    let target_dialog = request
+        .target_dialog_header()
+        .expect("Target-Dialog header")
+        .typed()?;
+    assert_eq!(target_dialog.call_id, "090459243588173445");
+    assert!(request.replaces_header().is_none());
+
+    Ok(())
+}
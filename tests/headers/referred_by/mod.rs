@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::ReferredBy;
+
+validate_untyped_header_trait!(ReferredBy);
+validate_to_typed_header_trait!(ReferredBy);
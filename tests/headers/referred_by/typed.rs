@@ -0,0 +1,67 @@
+use rsip::{
+    common::uri,
+    headers::{typed::ReferredBy, untyped::ToTypedHeader, UntypedHeader},
+};
+use std::convert::TryInto;
+
+validate_typed_header_trait!(ReferredBy);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() -> Result<(), rsip::Error> {
+        let uri: uri::Uri = "sip:referrer@referrer.example".try_into()?;
+
+        assert_eq!(
+            ReferredBy {
+                params: vec![uri::Param::Other(
+                    "cid".into(),
+                    Some("\"20398823@referrer.example\"".into())
+                )],
+                ..ReferredBy::from(uri)
+            }
+            .to_string(),
+            String::from("<sip:referrer@referrer.example>;cid=\"20398823@referrer.example\"")
+        );
+
+        Ok(())
+    }
+}
+
+mod try_from_untyped {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        let referred_by = rsip::headers::ReferredBy::new(
+            "\"Bob\" <sip:bob@biloxi.example.com> ;cid=\"2UWQFN309shb3@referrer.example\"",
+        )
+        .typed()?;
+
+        assert_eq!(referred_by.display_name, Some("\"Bob\"".into()));
+        assert_eq!(referred_by.uri.to_string(), "sip:bob@biloxi.example.com");
+        assert_eq!(
+            referred_by.params,
+            vec![uri::Param::Other(
+                "cid".into(),
+                Some("\"2UWQFN309shb3@referrer.example\"".into())
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() -> Result<(), rsip::Error> {
+        let referred_by = rsip::headers::ReferredBy::new("sip:bob@biloxi.example.com").typed()?;
+
+        assert_eq!(referred_by.display_name, None);
+        assert_eq!(
+            referred_by.to_string(),
+            String::from("<sip:bob@biloxi.example.com>")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use rsip::{
+    headers::{Custom, CustomHeader, HeaderRegistry},
+    prelude::*,
+    Header, SipMessage,
+};
+use rsip_derives::CustomHeader;
+use std::convert::TryFrom;
+
+#[derive(CustomHeader, Debug, PartialEq, Eq, Clone)]
+#[header(display_name = "X-Tenant", compact_name = "xt")]
+struct XTenant(String);
+
+impl std::str::FromStr for XTenant {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl std::fmt::Display for XTenant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(CustomHeader, Debug, PartialEq, Eq, Clone)]
+struct SessionLevel(u8);
+
+impl std::str::FromStr for SessionLevel {
+    type Err = rsip::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self(value.trim().parse::<u8>()?))
+    }
+}
+
+impl std::fmt::Display for SessionLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn registry() -> HeaderRegistry {
+    HeaderRegistry::new()
+        .with::<XTenant>()
+        .with::<SessionLevel>()
+}
+
+fn message() -> &'static str {
+    concat!(
+        "OPTIONS sip:bob@biloxi.com SIP/2.0\r\n",
+        "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+        "xt: acme\r\n",
+        "Session-Level: 3\r\n",
+        "X-Other: something\r\n",
+        "\r\n"
+    )
+}
+
+mod derive {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(XTenant::NAME, "X-Tenant");
+        assert_eq!(XTenant::COMPACT_NAME, Some("xt"));
+        assert_eq!(SessionLevel::NAME, "Session-Level");
+        assert_eq!(SessionLevel::COMPACT_NAME, None);
+    }
+
+    #[test]
+    fn into_header() {
+        let header: Header = XTenant("acme".into()).into();
+
+        assert_eq!(header, Header::Custom(Custom::new(XTenant("acme".into()))));
+        assert_eq!(header.to_string(), "X-Tenant: acme");
+        assert_eq!(header.to_compact_string(), "xt: acme");
+    }
+}
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn is_registered() {
+        let registry = registry();
+
+        assert!(registry.is_registered("x-tenant"));
+        assert!(registry.is_registered("XT"));
+        assert!(registry.is_registered("Session-Level"));
+        assert!(!registry.is_registered("X-Other"));
+    }
+
+    #[test]
+    fn parse() {
+        let sip_message = registry().parse(message()).unwrap();
+
+        assert_eq!(
+            sip_message.custom_header::<XTenant>(),
+            Some(&XTenant("acme".into()))
+        );
+        assert_eq!(
+            sip_message.custom_header::<SessionLevel>(),
+            Some(&SessionLevel(3))
+        );
+        assert!(sip_message
+            .headers()
+            .iter()
+            .any(|header| header == &Header::Other("X-Other".into(), "something".into())));
+    }
+
+    #[test]
+    fn without_registry() {
+        let sip_message = SipMessage::try_from(message()).unwrap();
+
+        assert_eq!(sip_message.custom_header::<XTenant>(), None);
+        assert!(sip_message
+            .headers()
+            .iter()
+            .any(|header| header == &Header::Other("xt".into(), "acme".into())));
+    }
+
+    #[test]
+    fn resolve() {
+        let mut sip_message = SipMessage::try_from(message()).unwrap();
+        registry().resolve(&mut sip_message).unwrap();
+
+        assert_eq!(sip_message, registry().parse(message()).unwrap());
+    }
+
+    #[test]
+    fn round_trip() {
+        let sip_message = registry().parse(message()).unwrap();
+
+        assert_eq!(sip_message.to_string(), message());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            registry().resolve_header(Header::Other("Session-Level".into(), "high".into())),
+            Err(rsip::Error::ParseError(
+                "invalid digit found in string".into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::Replaces;
+
+validate_untyped_header_trait!(Replaces);
+validate_to_typed_header_trait!(Replaces);
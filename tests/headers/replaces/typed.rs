@@ -0,0 +1,77 @@
+use rsip::{
+    common::uri::{param::Tag, Param},
+    headers::{
+        typed::{replaces::Tokenizer, Replaces, Tokenize},
+        untyped::ToTypedHeader,
+        UntypedHeader,
+    },
+};
+use std::convert::TryInto;
+
+validate_typed_header_trait!(Replaces);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            Replaces::new(
+                "98732@sip.example.com",
+                Tag::new("r33th4x0r"),
+                Tag::new("ff87ff")
+            )
+            .with_early_only()
+            .to_string(),
+            String::from("98732@sip.example.com;to-tag=r33th4x0r;from-tag=ff87ff;early-only")
+        );
+    }
+}
+
+mod try_from_tokenizer {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        let replaces: Replaces = Tokenizer::tokenize(
+            "425928@bobster.example.org ;to-tag=7743; from-tag=6472 ;early-only;x-foo=bar",
+        )?
+        .try_into()?;
+
+        assert_eq!(
+            replaces,
+            Replaces {
+                early_only: true,
+                params: vec![Param::Other("x-foo".into(), Some("bar".into()))],
+                ..Replaces::new(
+                    "425928@bobster.example.org",
+                    Tag::new("7743"),
+                    Tag::new("6472")
+                )
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() -> Result<(), rsip::Error> {
+        let replaces = rsip::headers::Replaces::new("abc@host;from-tag=2;to-tag=1").typed()?;
+
+        assert_eq!(replaces.to_tag, Tag::new("1"));
+        assert_eq!(replaces.from_tag, Tag::new("2"));
+        assert!(!replaces.early_only);
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_tag() {
+        assert!(rsip::headers::Replaces::new("abc@host;to-tag=1")
+            .typed()
+            .is_err());
+        assert!(rsip::headers::Replaces::new(";to-tag=1;from-tag=2")
+            .typed()
+            .is_err());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::RSeq;
+
+validate_untyped_header_trait!(RSeq);
+validate_to_typed_header_trait!(RSeq);
@@ -0,0 +1,20 @@
+use rsip::headers::{typed::RSeq, untyped::ToTypedHeader, Header, UntypedHeader};
+
+validate_typed_header_trait!(RSeq);
+
+#[test]
+fn display() {
+    assert_eq!(RSeq(988789).to_string(), String::from("988789"));
+    assert_eq!(
+        Header::from(RSeq(988789)).to_string(),
+        String::from("RSeq: 988789")
+    );
+}
+
+#[test]
+fn try_from_untyped() -> Result<(), rsip::Error> {
+    assert_eq!(rsip::headers::RSeq::new("988789").typed()?, RSeq(988789));
+    assert!(rsip::headers::RSeq::new("first").typed().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::SessionExpires;
+
+validate_untyped_header_trait!(SessionExpires);
+validate_to_typed_header_trait!(SessionExpires);
@@ -0,0 +1,57 @@
+use rsip::{
+    common::uri::Param,
+    headers::{
+        typed::{
+            session_expires::{Refresher, Tokenizer},
+            SessionExpires, Tokenize,
+        },
+        untyped::ToTypedHeader,
+        UntypedHeader,
+    },
+};
+use std::convert::TryInto;
+
+validate_typed_header_trait!(SessionExpires);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(SessionExpires::new(1800).to_string(), String::from("1800"));
+        assert_eq!(
+            SessionExpires::new(1800)
+                .with_refresher(Refresher::Uas)
+                .to_string(),
+            String::from("1800;refresher=uas")
+        );
+    }
+}
+
+mod try_from_tokenizer {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        let session_expires: SessionExpires =
+            Tokenizer::tokenize("4000 ;refresher=UAC;x-foo=bar")?.try_into()?;
+
+        assert_eq!(
+            session_expires,
+            SessionExpires {
+                params: vec![Param::Other("x-foo".into(), Some("bar".into()))],
+                ..SessionExpires::new(4000).with_refresher(Refresher::Uac)
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() {
+        assert!(rsip::headers::SessionExpires::new("1800;refresher=both")
+            .typed()
+            .is_err());
+        assert!(rsip::headers::SessionExpires::new("soon").typed().is_err());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::SubscriptionState;
+
+validate_untyped_header_trait!(SubscriptionState);
+validate_to_typed_header_trait!(SubscriptionState);
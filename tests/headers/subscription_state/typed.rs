@@ -0,0 +1,117 @@
+use rsip::{
+    common::uri::{self, Param},
+    headers::{
+        typed::{
+            subscription_state::{Reason, State, Tokenizer},
+            SubscriptionState, Tokenize,
+        },
+        untyped::ToTypedHeader,
+        UntypedHeader,
+    },
+};
+use std::convert::TryInto;
+
+validate_typed_header_trait!(SubscriptionState);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            SubscriptionState {
+                expires: Some(600),
+                ..SubscriptionState::new(State::Active)
+            }
+            .to_string(),
+            String::from("active;expires=600")
+        );
+    }
+
+    #[test]
+    fn display2() {
+        assert_eq!(
+            SubscriptionState {
+                reason: Some(Reason::Timeout),
+                retry_after: Some(30),
+                params: vec![Param::Other("x-reason".into(), Some("idle".into()))],
+                ..SubscriptionState::new(State::Terminated)
+            }
+            .to_string(),
+            String::from("terminated;reason=timeout;retry-after=30;x-reason=idle")
+        );
+    }
+}
+
+mod try_from_tokenizer {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        assert_eq!(
+            Tokenizer {
+                value: "terminated",
+                params: vec![
+                    uri::param::Tokenizer::from(("reason", Some("timeout"))),
+                    uri::param::Tokenizer::from(("retry-after", Some("30")))
+                ]
+            }
+            .try_into(),
+            Ok(SubscriptionState {
+                reason: Some(Reason::Timeout),
+                retry_after: Some(30),
+                ..SubscriptionState::new(State::Terminated)
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_2() -> Result<(), rsip::Error> {
+        let subscription_state: SubscriptionState =
+            Tokenize::tokenize("Pending ;expires=3600;reason=x-later")
+                .and_then(|tokenizer: Tokenizer| tokenizer.try_into())?;
+
+        assert_eq!(
+            subscription_state,
+            SubscriptionState {
+                reason: Some(Reason::Other("x-later".into())),
+                expires: Some(3600),
+                ..SubscriptionState::new(State::Pending)
+            }
+        );
+        assert!(!subscription_state.is_terminated());
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_3() {
+        let subscription_state: Result<SubscriptionState, rsip::Error> = Tokenizer {
+            value: "active",
+            params: vec![uri::param::Tokenizer::from(("expires", Some("soon")))],
+        }
+        .try_into();
+
+        assert!(subscription_state.is_err());
+    }
+
+    #[test]
+    fn try_from_4() -> Result<(), rsip::Error> {
+        let subscription_state =
+            rsip::headers::SubscriptionState::new("active;expires=60;foo").typed()?;
+
+        assert_eq!(
+            subscription_state,
+            SubscriptionState {
+                expires: Some(60),
+                params: vec![Param::Other("foo".into(), None)],
+                ..SubscriptionState::new(State::Active)
+            }
+        );
+        assert_eq!(subscription_state.to_string(), "active;expires=60;foo");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod typed;
+
+use rsip::headers::TargetDialog;
+
+validate_untyped_header_trait!(TargetDialog);
+validate_to_typed_header_trait!(TargetDialog);
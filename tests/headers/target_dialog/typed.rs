@@ -0,0 +1,51 @@
+use rsip::{
+    common::uri::param::Tag,
+    headers::{typed::TargetDialog, untyped::ToTypedHeader, UntypedHeader},
+};
+
+validate_typed_header_trait!(TargetDialog);
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            TargetDialog::new(
+                "fa77as7dad8-sd98ajzz@host.example.com",
+                Tag::new("1"),
+                Tag::new("2")
+            )
+            .to_string(),
+            String::from("fa77as7dad8-sd98ajzz@host.example.com;local-tag=1;remote-tag=2")
+        );
+    }
+}
+
+mod try_from_untyped {
+    use super::*;
+
+    #[test]
+    fn try_from_1() -> Result<(), rsip::Error> {
+        assert_eq!(
+            rsip::headers::TargetDialog::new(
+                "fa77as7dad8-sd98ajzz@host.example.com;remote-tag=2;local-tag=1"
+            )
+            .typed()?,
+            TargetDialog::new(
+                "fa77as7dad8-sd98ajzz@host.example.com",
+                Tag::new("1"),
+                Tag::new("2")
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_tag() {
+        assert!(rsip::headers::TargetDialog::new("abc@host;local-tag=1")
+            .typed()
+            .is_err());
+    }
+}
@@ -58,3 +58,97 @@ mod try_from_tokenizer {
         );
     }
 }
+
+//examples taken from RFC5118 (SIP Torture Test Messages for IPv6)
+mod rfc5118 {
+    use super::*;
+    use rsip::{
+        common::uri::{
+            param::{Maddr, Received},
+            Host,
+        },
+        headers::typed::Tokenize,
+    };
+    use std::{convert::TryFrom, net::IpAddr};
+
+    #[test]
+    fn sent_by() {
+        let via = Via::try_from(
+            Tokenizer::tokenize("SIP/2.0/UDP [2001:db8::9:1]:5060;branch=z9hG4bKas3-111").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            via.sent_by().host_with_port,
+            HostWithPort::from(("2001:db8::9:1".parse::<IpAddr>().unwrap(), 5060))
+        );
+        assert_eq!(
+            via.to_string(),
+            "SIP/2.0/UDP [2001:db8::9:1]:5060;branch=z9hG4bKas3-111"
+        );
+    }
+
+    #[test]
+    fn received() {
+        let expected: IpAddr = "2001:db8::9:255".parse().unwrap();
+
+        let via = Via::try_from(
+            Tokenizer::tokenize(
+                "SIP/2.0/UDP [2001:db8::9:1];received=[2001:db8::9:255];branch=z9hG4bKas3-111",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(via.received(), Ok(Some(expected)));
+
+        let via = Via::try_from(
+            Tokenizer::tokenize(
+                "SIP/2.0/UDP [2001:db8::9:1];received=2001:db8::9:255;branch=z9hG4bKas3-111",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(via.received(), Ok(Some(expected)));
+
+        assert_eq!(Received::from(expected).to_string(), "2001:db8::9:255");
+    }
+
+    #[test]
+    fn received_display() {
+        let via = Via::try_from(
+            Tokenizer::tokenize("SIP/2.0/UDP [2001:db8::9:1];received=2001:db8::9:1").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(via.to_string(), "SIP/2.0/UDP [2001:db8::9:1];received=2001:db8::9:1");
+
+        let mut via = via;
+        via.params = vec![Param::Received(Received::from(
+            "2001:db8::9:1".parse::<IpAddr>().unwrap(),
+        ))];
+        assert_eq!(via.to_string(), "SIP/2.0/UDP [2001:db8::9:1];received=2001:db8::9:1");
+        assert_eq!(via.received(), Ok(Some("2001:db8::9:1".parse().unwrap())));
+    }
+
+    #[test]
+    fn maddr() {
+        let via = Via::try_from(
+            Tokenizer::tokenize("SIP/2.0/UDP [2001:db8::9:1];maddr=[ff02::1];branch=z9hG4bK1")
+                .unwrap(),
+        )
+        .unwrap();
+
+        let maddr = via
+            .params
+            .iter()
+            .find_map(|param| match param {
+                Param::Maddr(maddr) => Some(maddr.clone()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(maddr.host(), Host::IpAddr("ff02::1".parse().unwrap()));
+        assert_eq!(
+            Maddr::from("ff02::1".parse::<IpAddr>().unwrap()),
+            Maddr::new("[ff02::1]")
+        );
+    }
+}
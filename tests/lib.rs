@@ -30,6 +30,7 @@ macro_rules! validate_typed_header_trait {
 }
 
 pub mod common;
+pub mod error;
 pub mod headers;
 pub mod message;
 pub mod services;
@@ -0,0 +1,311 @@
+use rsip::{
+    message::{request, Limit, Limits, ParseOptions},
+    Error, ErrorKind, SipMessage, StatusCode,
+};
+use std::convert::TryFrom;
+
+const INVITE: &str = concat!(
+    "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "To: Bob <sip:bob@biloxi.com>\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "Content-Length: 13\r\n\r\n",
+    "v=0\r\ns=-\r\nt=0"
+);
+
+fn parse(part: &[u8], limits: Limits) -> Result<SipMessage, Error> {
+    SipMessage::parse_with(part, &ParseOptions::default().with_limits(limits))
+        .map(|(sip_message, _)| sip_message)
+}
+
+fn with_header(header: &str) -> String {
+    INVITE.replace(
+        "Max-Forwards: 70\r\n",
+        &format!("Max-Forwards: 70\r\n{}\r\n", header),
+    )
+}
+
+mod check {
+    use super::*;
+
+    #[test]
+    fn within_limits() {
+        assert_eq!(
+            parse(INVITE.as_bytes(), Limits::default()),
+            Ok(SipMessage::try_from(INVITE).unwrap())
+        );
+    }
+
+    #[test]
+    fn message_size() {
+        let limits = Limits {
+            max_message_size: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parse(INVITE.as_bytes(), limits),
+            Err(Error::LimitExceeded(Limit::MessageSize, 100))
+        );
+    }
+
+    #[test]
+    fn headers() {
+        let limits = Limits {
+            max_headers: 7,
+            ..Default::default()
+        };
+
+        assert_eq!(parse(INVITE.as_bytes(), limits).map(|_| ()), Ok(()));
+        assert_eq!(
+            parse(with_header("Subject: foo").as_bytes(), limits),
+            Err(Error::LimitExceeded(Limit::Headers, 7))
+        );
+        assert_eq!(
+            parse(
+                with_header("Subject: foo\r\n bar").as_bytes(),
+                Limits {
+                    max_headers: 8,
+                    ..Default::default()
+                }
+            )
+            .map(|_| ()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn line_length() {
+        let limits = Limits {
+            max_line_length: 50,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parse(INVITE.as_bytes(), limits),
+            Err(Error::LimitExceeded(Limit::LineLength, 50))
+        );
+    }
+
+    #[test]
+    fn params_per_header() {
+        let limits = Limits {
+            max_params_per_header: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parse(
+                with_header("Contact: <sip:bob@biloxi.com;a>;c;d=\"x;y;z\"").as_bytes(),
+                limits
+            )
+            .map(|_| ()),
+            Ok(())
+        );
+        assert_eq!(
+            parse(
+                with_header("Contact: <sip:bob@biloxi.com;a;b>;c\r\n ;d").as_bytes(),
+                limits
+            ),
+            Err(Error::LimitExceeded(Limit::ParamsPerHeader, 3))
+        );
+    }
+
+    #[test]
+    fn uri_length() {
+        let limits = Limits {
+            max_uri_length: 10,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parse(INVITE.as_bytes(), limits),
+            Err(Error::LimitExceeded(Limit::UriLength, 10))
+        );
+        assert_eq!(
+            parse(b"SIP/2.0 200 OK\r\nContent-Length: 0\r\n\r\n", limits).map(|_| ()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn body_size() {
+        let limits = Limits {
+            max_body_size: 12,
+            ..Default::default()
+        };
+        let error = parse(INVITE.as_bytes(), limits).unwrap_err();
+
+        assert_eq!(error, Error::LimitExceeded(Limit::BodySize, 12));
+        assert_eq!(error.kind(), ErrorKind::LimitExceeded);
+        assert_eq!(
+            error.to_string(),
+            "rsip error: limit exceeded: body size (max 12)"
+        );
+    }
+
+    #[test]
+    fn status_code() {
+        assert_eq!(
+            Limit::BodySize.status_code(),
+            StatusCode::RequestEntityTooLarge
+        );
+        assert_eq!(
+            Limit::UriLength.status_code(),
+            StatusCode::RequestUriTooLong
+        );
+        assert_eq!(Limit::Headers.status_code(), StatusCode::MessageTooLarge);
+    }
+}
+
+//the limits enforced outside of parse_with
+mod enforced {
+    use super::*;
+    use rsip::message::stream::{decode, Decoded, Decoder};
+
+    #[test]
+    fn try_from() {
+        let message = with_header(&"X-Padding: a\r\n".repeat(300));
+
+        assert_eq!(
+            SipMessage::try_from(message.as_str()),
+            Err(Error::LimitExceeded(Limit::Headers, 256))
+        );
+        assert_eq!(
+            rsip::Request::try_from(message.as_str()),
+            Err(Error::LimitExceeded(Limit::Headers, 256))
+        );
+        assert!(parse(message.as_bytes(), Limits::unlimited()).is_ok());
+    }
+
+    #[test]
+    fn stream_head() {
+        let limits = Limits {
+            max_message_size: 200,
+            ..Default::default()
+        };
+        let mut decoder = Decoder::new(limits);
+        let mut stream = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n".to_vec();
+
+        assert_eq!(decoder.decode(&stream), Ok(Decoded::Incomplete));
+        while stream.len() <= 200 {
+            stream.extend_from_slice(b"X-Padding: a\r\n");
+        }
+        assert_eq!(
+            decoder.decode(&stream),
+            Err(Error::LimitExceeded(Limit::MessageSize, 200))
+        );
+        assert_eq!(
+            decode(&stream, &limits),
+            Err(Error::LimitExceeded(Limit::MessageSize, 200))
+        );
+
+        let message = with_header(&"X-Padding: a\r\n".repeat(300));
+        assert_eq!(
+            decode(message.as_bytes(), &Limits::default()),
+            Err(Error::LimitExceeded(Limit::Headers, 256))
+        );
+    }
+}
+
+//randomized inputs, checking that neither the limits nor the tokenizer panic and that hostile
+//messages are rejected by the limits
+mod fuzz {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    const ITERATIONS: usize = 2000;
+    const ALPHABET: &[u8] = b"INVITE sip:SIP/2.0\r\n\t;:,<>\"\\=@ abcz019%";
+
+    fn tokenize(part: &[u8]) {
+        let _ = request::Tokenizer::tokenize(part);
+        let _ = parse(part, Limits::default());
+        let _ = SipMessage::parse_with(part, &ParseOptions::lenient());
+        let _ = SipMessage::parse_with(part, &ParseOptions::strict());
+    }
+
+    fn mutate(rng: &mut StdRng, part: &mut Vec<u8>) {
+        for _ in 0..rng.gen_range(1..8) {
+            let index = rng.gen_range(0..part.len());
+            match rng.gen_range(0..3) {
+                0 => part[index] = ALPHABET[rng.gen_range(0..ALPHABET.len())],
+                1 => part.insert(index, ALPHABET[rng.gen_range(0..ALPHABET.len())]),
+                _ => {
+                    part.remove(index);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_bytes() {
+        let mut rng = StdRng::seed_from_u64(3261);
+
+        for _ in 0..ITERATIONS {
+            let len = rng.gen_range(0..256);
+            let part = (0..len).map(|_| rng.gen()).collect::<Vec<u8>>();
+            tokenize(&part);
+
+            let part = (0..len)
+                .map(|_| ALPHABET[rng.gen_range(0..ALPHABET.len())])
+                .collect::<Vec<u8>>();
+            tokenize(&part);
+        }
+    }
+
+    #[test]
+    fn mutated_messages() {
+        let mut rng = StdRng::seed_from_u64(4475);
+
+        for _ in 0..ITERATIONS {
+            let mut part = INVITE.as_bytes().to_vec();
+            mutate(&mut rng, &mut part);
+            tokenize(&part);
+        }
+    }
+
+    #[test]
+    fn hostile_messages() {
+        let mut rng = StdRng::seed_from_u64(5118);
+        let limits = Limits::default();
+
+        for _ in 0..50 {
+            let (part, limit) = match rng.gen_range(0..4) {
+                0 => (
+                    with_header(&"Subject: foo\r\n".repeat(rng.gen_range(256..2048))),
+                    Limit::Headers,
+                ),
+                1 => (
+                    with_header(&format!(
+                        "Subject: {}",
+                        "a".repeat(rng.gen_range(16..64) * 1024)
+                    )),
+                    Limit::LineLength,
+                ),
+                2 => (
+                    with_header(&format!(
+                        "Via: SIP/2.0/UDP a{}",
+                        ";b".repeat(rng.gen_range(65..8192))
+                    )),
+                    Limit::ParamsPerHeader,
+                ),
+                _ => (
+                    INVITE.replace(
+                        "sip:bob@",
+                        &format!("sip:{}@", "b".repeat(rng.gen_range(8..16) * 1024)),
+                    ),
+                    Limit::UriLength,
+                ),
+            };
+
+            match parse(part.as_bytes(), limits) {
+                Err(Error::LimitExceeded(exceeded, _)) => assert_eq!(exceeded, limit),
+                result => panic!("unexpected result: {:?}", result.map(|_| ())),
+            }
+            let _ = request::Tokenizer::tokenize(part.as_bytes());
+        }
+    }
+}
@@ -17,6 +17,7 @@ fn methods() {
         },
         params: vec![],
         headers: vec![].into(),
+        opaque: None,
     };
     let headers: rsip::Headers = vec![
                 Via::new("SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashd92").into(),
@@ -56,6 +57,63 @@ fn bytes() {
     );
 }
 
+mod write_to {
+    use super::*;
+    use bytes::BufMut;
+
+    const BODY: [u8; 6] = [0x00, 0xff, 0x1f, 0x8b, 0x0d, 0x0a];
+
+    fn message() -> SipMessage {
+        SipMessage::Request(Request {
+            method: common::method::Method::Message,
+            uri: uri::Uri {
+                scheme: Some(uri::scheme::Scheme::Sip),
+                host_with_port: uri::Host::Domain("biloxi.com".into()).into(),
+                ..Default::default()
+            },
+            version: common::version::Version::V2,
+            headers: vec![
+                ContentType::new("application/gzip").into(),
+                ContentLength::new("6").into(),
+            ]
+            .into(),
+            body: BODY.to_vec(),
+        })
+    }
+
+    #[test]
+    fn binary_body() {
+        let mut expected = b"MESSAGE sip:biloxi.com SIP/2.0\r\n\
+            Content-Type: application/gzip\r\n\
+            Content-Length: 6\r\n\r\n"
+            .to_vec();
+        expected.extend_from_slice(&BODY);
+
+        let mut written = vec![];
+        message().write_to(&mut written).unwrap();
+        assert_eq!(written, expected);
+
+        let mut buf = bytes::BytesMut::new().writer();
+        message().write_to(&mut buf).unwrap();
+        assert_eq!(buf.into_inner(), expected);
+
+        assert_eq!(Vec::<u8>::from(message()), expected);
+        assert_eq!(bytes::Bytes::from(message()), expected);
+        assert!(message().to_string().contains('\u{fffd}'));
+    }
+
+    #[test]
+    fn parsed_binary_body() -> Result<(), rsip::Error> {
+        let raw = Vec::<u8>::from(message());
+        let sip_message = SipMessage::try_from(raw.as_slice())?;
+
+        assert_eq!(sip_message.body(), &BODY.to_vec());
+        assert_eq!(bytes::Bytes::from(sip_message), raw);
+
+        Ok(())
+    }
+}
+
 mod display {
     use super::*;
 
@@ -91,7 +149,8 @@ mod parser {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![].into(),
@@ -130,7 +189,8 @@ mod parser {
                     port: None
                 },
                 params: vec![],
-                headers: vec![].into()
+                headers: vec![].into(),
+                opaque: None
             },
             version: common::version::Version::V2,
             headers: vec![
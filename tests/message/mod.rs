@@ -1,4 +1,10 @@
 pub mod has_headers;
+pub mod limits;
 pub mod message;
+pub mod parse_options;
 pub mod request;
 pub mod response;
+pub mod round_trip;
+pub mod stream;
+pub mod torture;
+pub mod sip_message_ref;
@@ -0,0 +1,196 @@
+use rsip::{
+    message::{HeadersExt, ParseOptions, ParseWarning},
+    Error, ErrorKind, Method, SipMessage,
+};
+use std::convert::TryFrom;
+
+const INVITE: &str = concat!(
+    "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "To: Bob <sip:bob@biloxi.com>\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "Content-Length: 13\r\n\r\n",
+    "v=0\r\ns=-\r\nt=0"
+);
+
+mod standard {
+    use super::*;
+
+    #[test]
+    fn same_as_try_from() {
+        let sloppy = INVITE.replace("CSeq: 314159 INVITE", "CSeq: 314159 BYE");
+
+        assert_eq!(
+            SipMessage::parse_with(sloppy.as_bytes(), &ParseOptions::default()),
+            Ok((SipMessage::try_from(sloppy.as_str()).unwrap(), vec![]))
+        );
+    }
+
+    #[test]
+    fn non_token_display_name() {
+        let invite = INVITE.replace("From: Alice", "From: alice@example.com");
+
+        assert!(SipMessage::parse_with(invite.as_bytes(), &ParseOptions::default()).is_ok());
+        assert_eq!(
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict())
+                .map_err(|error| error.kind()),
+            Err(ErrorKind::Tokenize)
+        );
+    }
+}
+
+mod strict {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            SipMessage::parse_with(INVITE.as_bytes(), &ParseOptions::strict()),
+            Ok((SipMessage::try_from(INVITE).unwrap(), vec![]))
+        );
+    }
+
+    #[test]
+    fn cseq_method() {
+        let invite = INVITE.replace("CSeq: 314159 INVITE", "CSeq: 314159 BYE");
+
+        assert_eq!(
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict()),
+            Err(Error::ParseError(
+                "CSeq method BYE does not match request method INVITE".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn content_length() {
+        let invite = INVITE.replace("Content-Length: 13", "Content-Length: 20");
+        let error = SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict())
+            .expect_err("content length mismatch");
+
+        assert_eq!(error.kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn extra_body_octets() {
+        let invite = INVITE.replace("Content-Length: 13", "Content-Length: 3");
+        let (sip_message, _) =
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict()).unwrap();
+
+        assert_eq!(sip_message.body(), &b"v=0".to_vec());
+    }
+
+    #[test]
+    fn repeated_header() {
+        let invite = INVITE.replace(
+            "Max-Forwards: 70\r\n",
+            "Max-Forwards: 70\r\nMax-Forwards: 69\r\n",
+        );
+
+        assert_eq!(
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict()),
+            Err(Error::ParseError("Max-Forwards header is repeated".into()))
+        );
+    }
+
+    #[test]
+    fn mandatory_headers() {
+        let invite = INVITE.replace("Max-Forwards: 70\r\n", "");
+
+        assert_eq!(
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict()),
+            Err(Error::MissingHeader("Max-Forwards".into()))
+        );
+    }
+
+    #[test]
+    fn rfc2543_request() {
+        let invite = INVITE
+            .replace("Max-Forwards: 70\r\n", "")
+            .replace(";branch=z9hG4bK776asdhds", "");
+
+        assert!(SipMessage::parse_with(invite.as_bytes(), &ParseOptions::strict()).is_ok());
+        assert_eq!(
+            SipMessage::parse_with(
+                invite.replace("CSeq", "Max-Forwards: a\r\nCSeq").as_bytes(),
+                &ParseOptions::strict()
+            )
+            .map_err(|error| error.kind()),
+            Err(ErrorKind::Parse)
+        );
+    }
+}
+
+mod lenient {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            SipMessage::parse_with(INVITE.as_bytes(), &ParseOptions::lenient()),
+            Ok((SipMessage::try_from(INVITE).unwrap(), vec![]))
+        );
+    }
+
+    #[test]
+    fn bare_line_feeds() {
+        let (head, body) = INVITE.split_at(INVITE.find("\r\n\r\n").unwrap() + 4);
+        let invite = format!("{}{}", head.replace("\r\n", "\n"), body);
+        let (sip_message, warnings) =
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::lenient()).unwrap();
+
+        assert_eq!(sip_message, SipMessage::try_from(INVITE).unwrap());
+        assert_eq!(warnings.len(), 9);
+        assert_eq!(warnings[0], ParseWarning::BareLineFeed { line: 1 });
+        assert_eq!(warnings[8], ParseWarning::BareLineFeed { line: 9 });
+    }
+
+    #[test]
+    fn sloppy_headers() {
+        let invite = INVITE
+            .replace("sip:bob@biloxi.com SIP/2.0", "sip:bob@biloxi.com sip/2.0")
+            .replace("CSeq: 314159 INVITE", "CSeq: 314159 INVITE \t");
+        let (sip_message, warnings) =
+            SipMessage::parse_with(invite.as_bytes(), &ParseOptions::lenient()).unwrap();
+
+        assert_eq!(sip_message, SipMessage::try_from(INVITE).unwrap());
+        assert_eq!(
+            warnings,
+            vec![
+                ParseWarning::LowercaseVersion,
+                ParseWarning::TrailingWhitespace { line: 7 },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_final_crlf() {
+        let response = concat!(
+            "sip/2.0 200 OK\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "CSeq: 314159 INVITE"
+        );
+        let (sip_message, warnings) =
+            SipMessage::parse_with(response.as_bytes(), &ParseOptions::lenient()).unwrap();
+
+        assert_eq!(
+            sip_message.cseq_header().unwrap().method(),
+            Ok(Method::Invite)
+        );
+        assert!(sip_message.body().is_empty());
+        assert_eq!(
+            warnings,
+            vec![
+                ParseWarning::LowercaseVersion,
+                ParseWarning::MissingFinalCrlf
+            ]
+        );
+        assert_eq!(
+            warnings[1].to_string(),
+            "headers are not terminated by an empty line"
+        );
+    }
+}
@@ -16,6 +16,7 @@ fn methods() {
         },
         params: vec![],
         headers: vec![].into(),
+        opaque: None,
     };
     let headers: rsip::headers::Headers = vec![
                 Via::new("SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashd92").into(),
@@ -57,7 +58,8 @@ fn bytes() {
                     port: None
                 },
                 params: vec![],
-                headers: vec![].into()
+                headers: vec![].into(),
+                opaque: None
             },
             version: common::version::Version::V2,
             headers: vec![].into(),
@@ -83,7 +85,8 @@ mod display {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![].into(),
@@ -107,7 +110,8 @@ mod display {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![
@@ -176,7 +180,8 @@ mod parser {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![].into(),
@@ -212,7 +217,8 @@ mod parser {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![
@@ -254,7 +260,8 @@ mod parser {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![
@@ -298,7 +305,8 @@ mod parser {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![
@@ -321,6 +329,22 @@ mod parser {
         );
     }
 
+    //the Record-Route line is kept as received, its values are compared one by one
+    fn split_record_route(request: Request) -> Request {
+        use rsip::message::HeadersExt;
+
+        let record_routes = request.record_route_values();
+        let mut headers: Vec<Header> = request.headers.clone().into();
+        let index = headers
+            .iter()
+            .position(|header| matches!(header, Header::RecordRoute(_)))
+            .unwrap_or(headers.len());
+        headers.retain(|header| !matches!(header, Header::RecordRoute(_)));
+        headers.splice(index..index, record_routes.into_iter().map(Into::into));
+
+        Request { headers: headers.into(), ..request }
+    }
+
     #[test]
     fn parser_invite69() {
         assert_eq!(
@@ -345,18 +369,19 @@ mod parser {
                 "and some complex: characters\r\n",
                 "Ok?"
             ).as_bytes()
-        ),
+        ).map(split_record_route),
         Ok(Request {
             method: common::method::Method::Invite,
             uri: uri::Uri {
                 scheme: Some(uri::scheme::Scheme::Tel),
                 auth: None,
                 host_with_port: uri::HostWithPort {
-                    host: uri::Host::Domain("tel:+48726152320".into()),
+                    host: uri::Host::Domain("+48726152320".into()),
                     port: None
                 },
                 params: vec![],
-                headers: vec![].into()
+                headers: vec![].into(),
+                opaque: None
             },
             version: common::version::Version::V2,
             headers: vec![
@@ -405,7 +430,8 @@ mod parser {
                     port: None
                 },
                 params: vec![],
-                headers: vec![].into()
+                headers: vec![].into(),
+                opaque: None
             },
             version: common::version::Version::V2,
             headers: vec![
@@ -459,7 +485,8 @@ mod parser {
                         port: None
                     },
                     params: vec![],
-                    headers: vec![].into()
+                    headers: vec![].into(),
+                    opaque: None
                 },
                 version: common::version::Version::V2,
                 headers: vec![
@@ -579,7 +606,7 @@ mod errors {
     use super::*;
 
     #[test]
-    fn method_parser_error() -> Result<(), rsip::Error> {
+    fn extension_method() -> Result<(), rsip::Error> {
         let req: &str = concat!(
                     "REGISTE sips:ss2.biloxi.example.com SIP/2.0\r\n",
                     "Via: SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashd92\r\n",
@@ -592,16 +619,11 @@ mod errors {
                     "Authorization: Digest username=\"bob\", realm=\"atlanta.example.com\" nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", opaque=\"\" uri=\"sips:ss2.biloxi.example.com\", response=\"dfe56131d1958046689d83306477ecc\"\r\n",
                     "Content-Length: 0\r\n\r\n"
                 );
-        let parsed_request = Request::try_from(req.clone().as_bytes());
-        match parsed_request {
-            Err(rsip::Error::ParseError(inner)) if inner.contains("invalid method: REGISTE") => {
-                Ok(())
-            }
-            _ => panic!(
-                "unexpected result, should have failed! {:?}",
-                parsed_request
-            ),
-        }
+        let request = Request::try_from(req.as_bytes())?;
+        assert_eq!(request.method, rsip::Method::Other("REGISTE".into()));
+        assert!(request.to_string().starts_with("REGISTE sips:ss2.biloxi.example.com SIP/2.0"));
+
+        Ok(())
     }
     /*
     #[test]
@@ -652,3 +674,234 @@ mod errors {
         }
     }
 }
+
+mod compact {
+    use super::*;
+    use rsip::message::HeadersExt;
+
+    #[test]
+    fn parser() -> Result<(), rsip::Error> {
+        let request = Request::try_from(concat!(
+            "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+            "v: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "Max-Forwards: 70\r\n",
+            "t: Bob <sip:bob@biloxi.com>\r\n",
+            "f: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "i: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "m: <sip:alice@pc33.atlanta.com>\r\n",
+            "l: 0\r\n\r\n"
+        ))?;
+
+        assert_eq!(
+            request.call_id_header()?,
+            &CallId::new("a84b4c76e66710@pc33.atlanta.com")
+        );
+        assert_eq!(
+            request.via_header()?,
+            &Via::new("SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds")
+        );
+        assert_eq!(request.to_header()?, &To::new("Bob <sip:bob@biloxi.com>"));
+        assert_eq!(
+            request.contact_header()?,
+            &Contact::new("<sip:alice@pc33.atlanta.com>")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> Result<(), rsip::Error> {
+        let request = Request::try_from(concat!(
+            "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: Bob <sip:bob@biloxi.com>\r\n",
+            "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ))?;
+
+        assert_eq!(
+            request.to_compact_string(),
+            concat!(
+                "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+                "v: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+                "Max-Forwards: 70\r\n",
+                "t: Bob <sip:bob@biloxi.com>\r\n",
+                "f: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+                "i: a84b4c76e66710@pc33.atlanta.com\r\n",
+                "CSeq: 314159 INVITE\r\n",
+                "l: 0\r\n\r\n"
+            )
+        );
+        assert_eq!(Request::try_from(request.to_compact_string())?, request);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_body() -> Result<(), rsip::Error> {
+        let mut bytes = concat!(
+            "MESSAGE sip:bob@biloxi.com SIP/2.0\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "Content-Length: 4\r\n\r\n"
+        )
+        .as_bytes()
+        .to_vec();
+        bytes.extend_from_slice(&[0xff, 0x00, 0xfe, 0x80]);
+        let request = Request::try_from(bytes.as_slice())?;
+
+        let mut compact = vec![];
+        request.write_compact_to(&mut compact).unwrap();
+        assert_eq!(
+            compact[..compact.len() - 4],
+            concat!(
+                "MESSAGE sip:bob@biloxi.com SIP/2.0\r\n",
+                "i: a84b4c76e66710@pc33.atlanta.com\r\n",
+                "l: 4\r\n\r\n"
+            )
+            .as_bytes()[..]
+        );
+        assert_eq!(Request::try_from(compact.as_slice())?, request);
+
+        let mut compact_message = vec![];
+        rsip::SipMessage::from(request)
+            .write_compact_to(&mut compact_message)
+            .unwrap();
+        assert_eq!(compact_message, compact);
+
+        Ok(())
+    }
+}
+
+mod folding {
+    use super::*;
+    use rsip::{message::HeadersExt, prelude::ToTypedHeader};
+
+    #[test]
+    fn parser() -> Result<(), rsip::Error> {
+        let request = Request::try_from(concat!(
+            "INVITE sip:watson@boston.bell-telephone.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "  ;received=192.0.2.1\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: <sip:watson@boston.bell-telephone.com>\r\n",
+            "From: <sip:a.g.bell@bell-telephone.com>;tag=a48s\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Contact: \"Mr. Watson\"\r\n",
+            "\t<sip:watson@worcester.bell-telephone.com>\r\n",
+            "\t;q=0.7; expires=3600\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ))?;
+
+        assert_eq!(
+            request.via_header()?,
+            &Via::new("SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds ;received=192.0.2.1")
+        );
+        assert_eq!(
+            request.via_header()?.typed()?.received(),
+            Ok(Some("192.0.2.1".parse().unwrap()))
+        );
+        assert_eq!(
+            request.contact_header()?,
+            &Contact::new("\"Mr. Watson\" <sip:watson@worcester.bell-telephone.com> ;q=0.7; expires=3600")
+        );
+        assert_eq!(
+            request.contact_header()?.typed()?.expires(),
+            Some(&rsip::param::Expires::new("3600"))
+        );
+        assert_eq!(request.cseq_header()?, &CSeq::new("314159 INVITE"));
+
+        Ok(())
+    }
+}
+
+mod route_set {
+    use super::*;
+    use rsip::message::HeadersExt;
+
+    fn message() -> &'static str {
+        concat!(
+            "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP a.com;branch=1, SIP/2.0/UDP b.com;branch=2\r\n",
+            "Record-Route: <sip:p1.com;lr>,<sip:p2.com;lr>\r\n",
+            "Record-Route: <sip:p3.com;lr>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        )
+    }
+
+    #[test]
+    fn parser() -> Result<(), rsip::Error> {
+        let request = Request::try_from(message())?;
+
+        assert_eq!(
+            request.record_route_values(),
+            vec![
+                RecordRoute::new("<sip:p1.com;lr>"),
+                RecordRoute::new("<sip:p2.com;lr>"),
+                RecordRoute::new("<sip:p3.com;lr>"),
+            ]
+        );
+        assert_eq!(request.via_values().len(), 2);
+        assert_eq!(request.to_string(), message());
+
+        Ok(())
+    }
+
+    #[test]
+    fn lines_as_received() -> Result<(), rsip::Error> {
+        let request = Request::try_from(message())?;
+
+        assert_eq!(
+            request.record_route_header(),
+            Some(&RecordRoute::new("<sip:p1.com;lr>,<sip:p2.com;lr>"))
+        );
+        assert_eq!(
+            request
+                .headers
+                .iter()
+                .filter(|header| matches!(header, Header::RecordRoute(_)))
+                .count(),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_and_combine() -> Result<(), rsip::Error> {
+        let mut request = Request::try_from(message())?;
+
+        request.headers.normalize();
+        assert_eq!(
+            request.headers,
+            vec![
+                Via::new("SIP/2.0/UDP a.com;branch=1").into(),
+                Via::new("SIP/2.0/UDP b.com;branch=2").into(),
+                RecordRoute::new("<sip:p1.com;lr>").into(),
+                RecordRoute::new("<sip:p2.com;lr>").into(),
+                RecordRoute::new("<sip:p3.com;lr>").into(),
+                ContentLength::new("0").into(),
+            ]
+            .into()
+        );
+        assert_eq!(request.record_route_values().len(), 3);
+
+        request.headers.combine();
+        assert_eq!(
+            request.headers,
+            vec![
+                Via::new("SIP/2.0/UDP a.com;branch=1, SIP/2.0/UDP b.com;branch=2").into(),
+                RecordRoute::new("<sip:p1.com;lr>, <sip:p2.com;lr>, <sip:p3.com;lr>").into(),
+                ContentLength::new("0").into(),
+            ]
+            .into()
+        );
+        assert_eq!(request.record_route_values().len(), 3);
+
+        Ok(())
+    }
+}
@@ -257,3 +257,37 @@ mod tokenizer {
         assert!(Tokenizer::tokenize("REGISTER sip:server.com SIP/2.0\r\n\r\n".as_bytes()).is_err());
     }
 }
+
+mod folding {
+    use super::*;
+    use rsip::message::HeadersExt;
+
+    #[test]
+    fn parser() -> Result<(), rsip::Error> {
+        let response = Response::try_from(concat!(
+            "SIP/2.0 200 OK\r\n",
+            "Via: SIP/2.0/UDP server10.biloxi.com\r\n",
+            " ;branch=z9hG4bKnashds8;received=192.0.2.3\r\n",
+            "Via: SIP/2.0/UDP bigbox3.site3.atlanta.com;branch=z9hG4bK77ef4c2312983.1\r\n",
+            "To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n",
+            "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Contact:\r\n",
+            "    <sip:bob@192.0.2.4>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ))?;
+
+        assert_eq!(
+            response.via_header()?,
+            &Via::new("SIP/2.0/UDP server10.biloxi.com ;branch=z9hG4bKnashds8;received=192.0.2.3")
+        );
+        assert_eq!(response.contact_header()?, &Contact::new("<sip:bob@192.0.2.4>"));
+        assert_eq!(
+            response.to_header()?,
+            &To::new("Bob <sip:bob@biloxi.com>;tag=a6c85cf")
+        );
+
+        Ok(())
+    }
+}
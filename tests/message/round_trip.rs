@@ -0,0 +1,160 @@
+use rsip::{
+    headers::{self, Header, UntypedHeader},
+    message::{stream, HasHeaders, HeadersExt},
+    Request, Response, SipMessage, StatusCode,
+};
+use std::convert::TryFrom;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com;transport=TCP SIP/2.0\r\n\
+v:SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards :  70\r\n\
+To: Bob\r\n <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+i: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq:314159 INVITE\r\n\
+Content-Length: 4\r\n\r\n\
+\xff\x00\xfe\x01";
+
+const RESPONSE: &[u8] = b"SIP/2.0 180 Ringing, please wait\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+CSeq:    314159 INVITE\r\n\
+l: 0\r\n\r\n";
+
+#[test]
+fn unmodified() -> Result<(), rsip::Error> {
+    let request = Request::try_from(INVITE)?;
+    assert_eq!(Vec::<u8>::from(request.clone()), INVITE);
+    assert_eq!(bytes::Bytes::from(request), INVITE);
+
+    let response = Response::try_from(RESPONSE)?;
+    assert_eq!(response.status_code, StatusCode::Ringing);
+    assert_eq!(response.to_string().as_bytes(), RESPONSE);
+
+    let sip_message = SipMessage::try_from(INVITE)?;
+    assert_eq!(Vec::<u8>::from(sip_message), INVITE);
+
+    Ok(())
+}
+
+#[test]
+fn from_stream() -> Result<(), rsip::Error> {
+    match stream::decode(INVITE, &Default::default())? {
+        stream::Decoded::Complete(sip_message, _) => {
+            assert_eq!(Vec::<u8>::from(sip_message), INVITE)
+        }
+        stream::Decoded::Incomplete => panic!("expected a complete message"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn modified_header() -> Result<(), rsip::Error> {
+    let mut request = Request::try_from(INVITE)?;
+    for header in request.headers_mut().iter_mut() {
+        if let Header::MaxForwards(max_forwards) = header {
+            *max_forwards = headers::MaxForwards::new("69");
+        }
+    }
+    request
+        .headers_mut()
+        .push(headers::UserAgent::new("rsip").into());
+
+    let expected = String::from_utf8_lossy(INVITE)
+        .replace("Max-Forwards :  70", "Max-Forwards: 69")
+        .replace(
+            "Content-Length: 4\r\n",
+            "Content-Length: 4\r\nUser-Agent: rsip\r\n",
+        );
+    assert_eq!(request.to_string(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn removed_and_reordered_headers() -> Result<(), rsip::Error> {
+    let mut request = Request::try_from(INVITE)?;
+    let call_id = request.call_id_header()?.clone();
+    request
+        .headers_mut()
+        .retain(|header| !matches!(header, Header::CallId(_)) && !matches!(header, Header::Via(_)));
+    request.headers_mut().push(call_id.into());
+
+    let expected = String::from_utf8_lossy(INVITE)
+        .replace(
+            "v:SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "",
+        )
+        .replace("i: a84b4c76e66710@pc33.atlanta.com\r\n", "")
+        .replace(
+            "Content-Length: 4\r\n",
+            "Content-Length: 4\r\ni: a84b4c76e66710@pc33.atlanta.com\r\n",
+        );
+    assert_eq!(request.to_string(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn modified_start_line() -> Result<(), rsip::Error> {
+    let mut request = Request::try_from(INVITE)?;
+    request.uri.params.clear();
+
+    assert!(request
+        .to_string()
+        .starts_with("INVITE sip:bob@biloxi.com SIP/2.0\r\nv:SIP/2.0/UDP"));
+
+    let mut response = Response::try_from(RESPONSE)?;
+    response.status_code = StatusCode::OK;
+
+    assert!(response.to_string().starts_with("SIP/2.0 200 OK\r\nVia:"));
+
+    Ok(())
+}
+
+#[test]
+fn equality() -> Result<(), rsip::Error> {
+    let request = Request::try_from(INVITE)?;
+    let rebuilt = Request {
+        method: request.method.clone(),
+        uri: request.uri.clone(),
+        version: request.version.clone(),
+        headers: request.headers.iter().cloned().collect::<Vec<_>>().into(),
+        body: request.body.clone(),
+    };
+
+    assert_eq!(request, rebuilt);
+    assert!(rebuilt.to_string().contains("Via: SIP/2.0/UDP"));
+
+    Ok(())
+}
+
+#[test]
+fn non_utf8_header() -> Result<(), rsip::Error> {
+    let at = INVITE.windows(5).position(|w| w == b"Bob\r\n").unwrap() + 3;
+    let invite = [&INVITE[..at], b"\xe9", &INVITE[at..]].concat();
+    let request = Request::try_from(invite.as_slice())?;
+
+    assert!(request.to_header().is_err());
+    assert_eq!(
+        request.from_header()?.value(),
+        "Alice <sip:alice@atlanta.com>;tag=1928301774"
+    );
+    assert!(request.headers.iter().any(|header| matches!(
+        header,
+        Header::NonUtf8(name, value) if name == "To" && value.starts_with(b"Bob\xe9\r\n ")
+    )));
+    assert_eq!(Vec::<u8>::from(request.clone()), invite);
+
+    let rebuilt = Request {
+        headers: request.headers.iter().cloned().collect::<Vec<_>>().into(),
+        ..request
+    };
+    let mut written = vec![];
+    rebuilt.write_to(&mut written).unwrap();
+    assert!(written
+        .windows(10)
+        .any(|window| window == b"To: Bob\xe9\r\n"));
+
+    Ok(())
+}
@@ -0,0 +1,228 @@
+use rsip::{
+    headers::{self, typed, HeaderRef},
+    message::{HeadersRefExt, RequestRef, ResponseRef, SipMessageRef},
+    prelude::UntypedHeader,
+    Method, Request, Response, SipMessage, StatusCode,
+};
+use std::convert::TryFrom;
+
+const INVITE: &str = concat!(
+    "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    "v: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "To: Bob <sip:bob@biloxi.com>\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "Contact: <sip:alice@pc33.atlanta.com>\r\n",
+    "m: <sip:alice@192.0.2.4>\r\n",
+    "X-Custom: something\r\n",
+    "Content-Length: 13\r\n\r\n",
+    "v=0\r\ns=-\r\nt=0"
+);
+
+const RINGING: &str = concat!(
+    "SIP/2.0 180 Ringing\r\n",
+    "Via: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "l: 0\r\n\r\n"
+);
+
+#[test]
+fn request() -> Result<(), rsip::Error> {
+    let request = RequestRef::try_from(INVITE.as_bytes())?;
+
+    assert_eq!(request.method(), &Method::Invite);
+    assert_eq!(request.uri()?, rsip::Uri::try_from("sip:bob@biloxi.com")?);
+    assert_eq!(request.body(), b"v=0\r\ns=-\r\nt=0");
+    assert_eq!(
+        request.via_header()?,
+        HeaderRef {
+            name: "v",
+            value: b"SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds"
+        }
+    );
+    assert_eq!(
+        request.call_id_header()?.value()?,
+        "a84b4c76e66710@pc33.atlanta.com"
+    );
+    assert_eq!(request.max_forwards_header()?.value()?, "70");
+    assert_eq!(request.contact_headers().len(), 2);
+    assert_eq!(
+        request.header("x-custom").map(|h| h.value_bytes()),
+        Some(&b"something"[..])
+    );
+    assert_eq!(request.route_header(), None);
+
+    Ok(())
+}
+
+#[test]
+fn borrows_input() -> Result<(), rsip::Error> {
+    let request = RequestRef::try_from(INVITE)?;
+    let input = INVITE.as_bytes().as_ptr_range();
+
+    let value = request.call_id_header()?.value()?;
+    assert!(input.contains(&value.as_ptr()));
+    assert!(input.contains(&request.body().as_ptr()));
+
+    Ok(())
+}
+
+#[test]
+fn typed() -> Result<(), rsip::Error> {
+    let request = RequestRef::try_from(INVITE)?;
+
+    assert_eq!(
+        request.cseq_header()?.typed::<typed::CSeq>()?,
+        typed::CSeq {
+            seq: 314159,
+            method: Method::Invite
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn response() -> Result<(), rsip::Error> {
+    let response = ResponseRef::try_from(RINGING)?;
+
+    assert_eq!(response.status_code(), &StatusCode::Ringing);
+    assert_eq!(
+        response.content_length_header().map(|h| h.value_bytes()),
+        Some(&b"0"[..])
+    );
+    assert_eq!(response.body(), b"");
+
+    Ok(())
+}
+
+#[test]
+fn sip_message() -> Result<(), rsip::Error> {
+    let message = SipMessageRef::try_from(RINGING)?;
+    assert!(message.is_response());
+    assert_eq!(
+        message.to_header()?.value()?,
+        "Bob <sip:bob@biloxi.com>;tag=a6c85cf"
+    );
+
+    let message = SipMessageRef::try_from(INVITE)?;
+    assert!(message.is_request());
+    assert!(message.expires_header().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn missing_header() -> Result<(), rsip::Error> {
+    let message = SipMessageRef::try_from("SIP/2.0 200 OK\r\n\r\n")?;
+
+    assert_eq!(
+        message.via_header(),
+        Err(rsip::Error::MissingHeader("Via".into()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn into_owned() -> Result<(), rsip::Error> {
+    assert_eq!(
+        Request::try_from(RequestRef::try_from(INVITE)?)?,
+        Request::try_from(INVITE)?
+    );
+    assert_eq!(
+        Response::try_from(ResponseRef::try_from(RINGING)?)?,
+        Response::try_from(RINGING)?
+    );
+    assert_eq!(
+        SipMessage::try_from(SipMessageRef::try_from(INVITE)?)?,
+        SipMessage::try_from(INVITE)?
+    );
+    assert_eq!(
+        rsip::Header::try_from(HeaderRef {
+            name: "Max-Forwards",
+            value: b"70"
+        })?,
+        rsip::Header::MaxForwards(headers::MaxForwards::new("70"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn typed_folded() -> Result<(), rsip::Error> {
+    use rsip::prelude::{HeadersExt, ToTypedHeader};
+
+    let invite = INVITE.replace(
+        "v: SIP/2.0/TCP pc33.atlanta.com;",
+        "v: SIP/2.0/TCP\r\n pc33.atlanta.com\r\n\t;",
+    );
+    let request = RequestRef::try_from(invite.as_str())?;
+
+    assert_eq!(
+        request.via_header()?.typed::<typed::Via>()?,
+        Request::try_from(invite.as_str())?.via_header()?.typed()?
+    );
+    assert_eq!(
+        request
+            .via_header()?
+            .typed::<typed::Via>()?
+            .uri
+            .host_with_port,
+        rsip::HostWithPort::from(rsip::Domain::from("pc33.atlanta.com"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn non_utf8_header() -> Result<(), rsip::Error> {
+    let mut bytes = INVITE.as_bytes().to_vec();
+    let index = INVITE.find("something").unwrap();
+    bytes[index] = 0xF6;
+
+    let request = RequestRef::try_from(&bytes[..])?;
+    let custom = request.header("X-Custom").unwrap();
+    assert_eq!(custom.value_bytes(), b"\xF6omething");
+    assert!(custom.value().is_err());
+
+    assert_eq!(Request::try_from(request)?, Request::try_from(&bytes[..])?);
+
+    Ok(())
+}
+
+#[test]
+fn into_owned_keeps_raw() -> Result<(), rsip::Error> {
+    let invite = INVITE.replace("Max-Forwards: 70", "Max-Forwards:   70");
+
+    let request = Request::try_from(RequestRef::try_from(invite.as_str())?)?;
+    assert_eq!(Vec::<u8>::from(request), invite.as_bytes());
+
+    let message = SipMessage::try_from(SipMessageRef::try_from(RINGING)?)?;
+    assert_eq!(Vec::<u8>::from(message), RINGING.as_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn from_tokenizer_keeps_no_raw() -> Result<(), rsip::Error> {
+    use rsip::message::request::Tokenizer;
+
+    let invite = INVITE.replace("Max-Forwards: 70", "Max-Forwards:   70");
+    let (_, tokenizer) = Tokenizer::tokenize(invite.as_bytes()).map_err(rsip::Error::from)?;
+
+    let request = Request::try_from(RequestRef::try_from(tokenizer)?)?;
+    assert_eq!(
+        request.to_string(),
+        INVITE
+            .replace("\nv: ", "\nVia: ")
+            .replace("\nm: ", "\nContact: ")
+    );
+
+    Ok(())
+}
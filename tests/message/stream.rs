@@ -0,0 +1,162 @@
+use rsip::{
+    message::{
+        stream::{decode, Decoded, Decoder},
+        Limit, Limits,
+    },
+    Response, SipMessage,
+};
+use std::convert::TryFrom;
+
+const INVITE: &str = concat!(
+    "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    "Via: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "To: Bob <sip:bob@biloxi.com>\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "Content-Type: application/sdp\r\n",
+    "Content-Length: 13\r\n\r\n",
+    "v=0\r\ns=-\r\nt=0"
+);
+
+const RINGING: &str = concat!(
+    "SIP/2.0 180 Ringing\r\n",
+    "Via: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "l: 0\r\n\r\n"
+);
+
+#[test]
+fn complete() -> Result<(), rsip::Error> {
+    assert_eq!(
+        decode(INVITE.as_bytes(), &Limits::default())?,
+        Decoded::Complete(SipMessage::try_from(INVITE)?, INVITE.len())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pipelined() -> Result<(), rsip::Error> {
+    let stream = format!("{}{}", INVITE, RINGING);
+
+    let consumed = match decode(stream.as_bytes(), &Limits::default())? {
+        Decoded::Complete(message, consumed) => {
+            assert_eq!(message, SipMessage::try_from(INVITE)?);
+            assert_eq!(message.body(), &b"v=0\r\ns=-\r\nt=0".to_vec());
+            consumed
+        }
+        Decoded::Incomplete => panic!("expected a complete message"),
+    };
+    assert_eq!(consumed, INVITE.len());
+
+    assert_eq!(
+        decode(&stream.as_bytes()[consumed..], &Limits::default())?,
+        Decoded::Complete(Response::try_from(RINGING)?.into(), RINGING.len())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn incomplete() -> Result<(), rsip::Error> {
+    //headers not there yet
+    assert_eq!(
+        decode(&INVITE.as_bytes()[..60], &Limits::default())?,
+        Decoded::Incomplete
+    );
+    //body not there yet
+    assert_eq!(
+        decode(&INVITE.as_bytes()[..INVITE.len() - 1], &Limits::default())?,
+        Decoded::Incomplete
+    );
+    assert_eq!(decode(b"", &Limits::default())?, Decoded::Incomplete);
+
+    Ok(())
+}
+
+#[test]
+fn keep_alives() -> Result<(), rsip::Error> {
+    assert_eq!(
+        decode(b"\r\n\r\n", &Limits::default())?,
+        Decoded::Incomplete
+    );
+
+    let stream = format!("\r\n\r\n\r\n{}", RINGING);
+    assert_eq!(
+        decode(stream.as_bytes(), &Limits::default())?,
+        Decoded::Complete(Response::try_from(RINGING)?.into(), stream.len())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn missing_content_length() {
+    let stream = concat!(
+        "SIP/2.0 180 Ringing\r\n",
+        "Via: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+        "CSeq: 314159 INVITE\r\n\r\n"
+    );
+
+    assert_eq!(
+        decode(stream.as_bytes(), &Limits::default()),
+        Err(rsip::Error::MissingHeader("Content-Length".into()))
+    );
+}
+
+#[test]
+fn invalid_content_length() {
+    let stream = concat!(
+        "SIP/2.0 180 Ringing\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "Content-Length: abc\r\n\r\n"
+    );
+
+    assert!(matches!(
+        decode(stream.as_bytes(), &Limits::default()),
+        Err(rsip::Error::ParseError(_))
+    ));
+}
+
+#[test]
+fn decoder() -> Result<(), rsip::Error> {
+    let mut decoder = Decoder::new(Limits::default());
+    let stream = format!("{}{}", INVITE, RINGING);
+    let bytes = stream.as_bytes();
+
+    for len in [30, 100, INVITE.len() - 5, INVITE.len() - 1] {
+        assert_eq!(decoder.decode(&bytes[..len])?, Decoded::Incomplete);
+    }
+    assert_eq!(
+        decoder.decode(&bytes[..INVITE.len() + 10])?,
+        Decoded::Complete(SipMessage::try_from(INVITE)?, INVITE.len())
+    );
+    assert_eq!(
+        decoder.decode(&bytes[INVITE.len()..])?,
+        Decoded::Complete(Response::try_from(RINGING)?.into(), RINGING.len())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn body_too_large() {
+    let stream = concat!(
+        "SIP/2.0 180 Ringing\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "Content-Length: 4294967295\r\n\r\n"
+    );
+
+    assert_eq!(
+        decode(stream.as_bytes(), &Limits::default()),
+        Err(rsip::Error::LimitExceeded(
+            Limit::BodySize,
+            Limits::default().max_body_size
+        ))
+    );
+}
@@ -0,0 +1,80 @@
+//! The torture test messages of RFC4475 and RFC5118, found under `tests/fixtures`.
+//!
+//! * `valid` messages must parse in both standard and strict mode and be written back byte for
+//!   byte.
+//! * `semantic` messages are well formed but break RFC3261 rules, so only strict mode rejects
+//!   them.
+//! * `invalid` messages must be rejected, by standard mode already or by strict mode.
+
+use rsip::{message::ParseOptions, SipMessage};
+use std::{convert::TryFrom, path::PathBuf};
+
+fn fixtures(class: &str) -> Vec<(PathBuf, Vec<u8>)> {
+    let mut fixtures = vec![];
+
+    for rfc in &["rfc4475", "rfc5118"] {
+        let dir = format!(
+            "{}/tests/fixtures/{}/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            rfc,
+            class
+        );
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let bytes = std::fs::read(&path).unwrap();
+            fixtures.push((path, bytes));
+        }
+    }
+
+    assert!(!fixtures.is_empty(), "no {} fixtures found", class);
+    fixtures
+}
+
+#[test]
+fn valid() {
+    for (path, bytes) in fixtures("valid") {
+        let sip_message = SipMessage::try_from(bytes.as_slice())
+            .unwrap_or_else(|error| panic!("{:?}: {}", path, error));
+        SipMessage::parse_with(&bytes, &ParseOptions::strict())
+            .unwrap_or_else(|error| panic!("{:?} (strict): {}", path, error));
+
+        assert_eq!(
+            Vec::<u8>::from(sip_message),
+            bytes,
+            "{:?} does not round-trip",
+            path
+        );
+    }
+}
+
+#[test]
+fn semantic() {
+    for (path, bytes) in fixtures("semantic") {
+        assert!(
+            SipMessage::try_from(bytes.as_slice()).is_ok(),
+            "{:?} should parse",
+            path
+        );
+        assert!(
+            SipMessage::parse_with(&bytes, &ParseOptions::strict()).is_err(),
+            "{:?} should be rejected in strict mode",
+            path
+        );
+    }
+}
+
+#[test]
+fn invalid() {
+    for (path, bytes) in fixtures("invalid") {
+        assert!(
+            SipMessage::parse_with(&bytes, &ParseOptions::strict()).is_err(),
+            "{:?} should be rejected",
+            path
+        );
+    }
+}
@@ -1 +1,4 @@
 pub mod auth;
+pub mod prack;
+pub mod privacy;
+pub mod session_timer;
@@ -0,0 +1,117 @@
+use rsip::{
+    headers::{typed::RAck, untyped::ToTypedHeader, Route, UntypedHeader},
+    message::HeadersExt,
+    services::prack,
+    Method, Request, Response, StatusCode,
+};
+use std::convert::TryFrom;
+
+fn invite() -> Request {
+    Request::try_from(concat!(
+        "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n",
+        "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+        "Max-Forwards: 70\r\n",
+        "To: Bob <sip:bob@biloxi.example.com>\r\n",
+        "From: Alice <sip:alice@atlanta.example.com>;tag=1928301774\r\n",
+        "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "Supported: 100rel\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))
+    .unwrap()
+}
+
+fn ringing() -> Response {
+    Response::try_from(concat!(
+        "SIP/2.0 180 Ringing\r\n",
+        "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+        "Record-Route: <sip:p1.example.com;lr>\r\n",
+        "Record-Route: <sip:p2.example.com;lr>, <sip:p3.example.com;lr>\r\n",
+        "To: Bob <sip:bob@biloxi.example.com>;tag=a6c85cf\r\n",
+        "From: Alice <sip:alice@atlanta.example.com>;tag=1928301774\r\n",
+        "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "Contact: <sip:bob@192.0.2.4>\r\n",
+        "Require: 100rel\r\n",
+        "RSeq: 813520\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn from_reliable_response() -> Result<(), rsip::Error> {
+    let invite = invite();
+    let prack = prack(&invite, &ringing())?;
+
+    assert_eq!(prack.method, Method::PRack);
+    assert_eq!(prack.uri.to_string(), "sip:bob@192.0.2.4");
+    assert_eq!(
+        prack.route_values(),
+        vec![
+            Route::new("<sip:p3.example.com;lr>"),
+            Route::new("<sip:p2.example.com;lr>"),
+            Route::new("<sip:p1.example.com;lr>")
+        ]
+    );
+    assert_eq!(
+        prack.to_header()?.value(),
+        "Bob <sip:bob@biloxi.example.com>;tag=a6c85cf"
+    );
+    assert_eq!(prack.from_header()?, invite.from_header()?);
+    assert_eq!(prack.call_id_header()?, invite.call_id_header()?);
+
+    let cseq = prack.cseq_header()?.typed()?;
+    assert_eq!((cseq.seq, cseq.method), (314160, Method::PRack));
+    assert_eq!(
+        prack.rack_header().expect("RAck header").typed()?,
+        RAck::from((813520, 314159, Method::Invite))
+    );
+
+    let branch = prack.via_header()?.typed()?.branch()?.clone();
+    assert_ne!(branch, invite.via_header()?.typed()?.branch()?.clone());
+
+    Ok(())
+}
+
+#[test]
+fn unreliable_response() {
+    let mut response = ringing();
+    response
+        .headers
+        .retain(|header| !matches!(header, rsip::Header::RSeq(_)));
+    assert!(prack(&invite(), &response).is_err());
+
+    let mut response = ringing();
+    response.status_code = StatusCode::OK;
+    assert!(prack(&invite(), &response).is_err());
+}
+
+#[test]
+fn without_100rel() -> Result<(), rsip::Error> {
+    let mut response = ringing();
+    response
+        .headers
+        .retain(|header| !matches!(header, rsip::Header::Require(_)));
+    assert!(prack(&invite(), &response).is_err());
+
+    let mut response = ringing();
+    response
+        .headers
+        .push(rsip::headers::Require::new("timer").into());
+    response
+        .headers
+        .retain(|header| header != &rsip::headers::Require::new("100rel").into());
+    assert!(prack(&invite(), &response).is_err());
+
+    let mut response = ringing();
+    response
+        .headers
+        .retain(|header| !matches!(header, rsip::Header::Require(_)));
+    response
+        .headers
+        .push(rsip::headers::Require::new("timer, 100rel").into());
+    prack(&invite(), &response)?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use rsip::{
+    headers::{untyped::ToTypedHeader, UntypedHeader},
+    message::HeadersExt,
+    services::apply_privacy,
+    Header, Request,
+};
+use std::convert::TryFrom;
+
+fn invite(privacy: &str) -> Request {
+    Request::try_from(format!(
+        concat!(
+            "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "From: \"Alice\" <sip:alice@atlanta.example.com>;tag=1928301774;x-foo=bar\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Subject: Lunch\r\n",
+            "User-Agent: Softphone 1.0\r\n",
+            "P-Asserted-Identity: \"Alice\" <sip:alice@atlanta.example.com>\r\n",
+            "P-Preferred-Identity: <sip:alice@atlanta.example.com>\r\n",
+            "{}",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        privacy
+    ))
+    .unwrap()
+}
+
+#[test]
+fn user_privacy() -> Result<(), rsip::Error> {
+    let mut request = invite("Privacy: user\r\n");
+    apply_privacy(&mut request)?;
+
+    assert_eq!(
+        request.from_header()?.value(),
+        "\"Anonymous\" <sip:anonymous@anonymous.invalid>;tag=1928301774"
+    );
+    assert_eq!(
+        request.from_header()?.typed()?.tag(),
+        invite("").from_header()?.typed()?.tag()
+    );
+    assert!(!request
+        .headers
+        .iter()
+        .any(|header| matches!(header, Header::Subject(_) | Header::UserAgent(_))));
+    assert!(request.p_asserted_identity_header().is_some());
+    assert!(request.p_preferred_identity_header().is_none());
+    assert!(request.privacy_header().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn id_privacy() -> Result<(), rsip::Error> {
+    let mut request = invite("Privacy: id\r\n");
+    apply_privacy(&mut request)?;
+
+    assert_eq!(request.from_header()?, invite("").from_header()?);
+    assert!(request.p_asserted_identity_header().is_none());
+    assert!(request.p_preferred_identity_header().is_none());
+    assert!(request.user_agent_header().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn no_privacy() -> Result<(), rsip::Error> {
+    for privacy in &["", "Privacy: none\r\n"] {
+        let mut request = invite(privacy);
+        let original = request.clone();
+        apply_privacy(&mut request)?;
+
+        assert_eq!(request, original);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn critical_privacy() -> Result<(), rsip::Error> {
+    let mut request = invite("Privacy: user;id;critical\r\n");
+    apply_privacy(&mut request)?;
+    assert!(request.p_asserted_identity_header().is_none());
+
+    for privacy in &["header;critical", "user;session;critical"] {
+        let mut request = invite(&format!("Privacy: {}\r\n", privacy));
+        let original = request.clone();
+
+        assert!(matches!(
+            apply_privacy(&mut request),
+            Err(rsip::Error::Unexpected(_))
+        ));
+        assert_eq!(request, original);
+    }
+
+    let mut request = invite("Privacy: header\r\n");
+    apply_privacy(&mut request)?;
+    assert!(request.user_agent_header().is_none());
+
+    Ok(())
+}
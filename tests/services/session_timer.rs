@@ -0,0 +1,101 @@
+use rsip::{
+    headers::{
+        typed::{session_expires::Refresher, MinSe},
+        untyped::ToTypedHeader,
+    },
+    message::HeadersExt,
+    services::SessionTimer,
+    Request, Response, StatusCode,
+};
+use std::convert::TryFrom;
+
+fn invite(session_expires: &str) -> Request {
+    Request::try_from(format!(
+        concat!(
+            "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Supported: timer\r\n",
+            "{}",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        session_expires
+    ))
+    .unwrap()
+}
+
+fn ok(session_expires: &str) -> Response {
+    Response::try_from(format!(
+        concat!(
+            "SIP/2.0 200 OK\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>;tag=a6c85cf\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "{}",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        session_expires
+    ))
+    .unwrap()
+}
+
+#[test]
+fn negotiate() -> Result<(), rsip::Error> {
+    let invite = invite("Session-Expires: 1800;refresher=uac\r\n");
+
+    assert_eq!(
+        SessionTimer::negotiate(&invite, &ok("x: 1200;refresher=uas\r\n"))?,
+        Some(SessionTimer {
+            interval: 1200,
+            refresher: Refresher::Uas
+        })
+    );
+    assert_eq!(
+        SessionTimer::negotiate(&invite, &ok("Session-Expires: 1800\r\n"))?,
+        Some(SessionTimer {
+            interval: 1800,
+            refresher: Refresher::Uac
+        })
+    );
+    assert_eq!(SessionTimer::negotiate(&invite, &ok(""))?, None);
+
+    let timer = SessionTimer::negotiate(&invite, &ok("Session-Expires: 1800\r\n"))?.unwrap();
+    assert_eq!(timer.refresh_after(), 900);
+
+    Ok(())
+}
+
+#[test]
+fn negotiate_non_2xx() {
+    let mut response = ok("Session-Expires: 1800;refresher=uas\r\n");
+    response.status_code = StatusCode::Ringing;
+
+    assert!(SessionTimer::negotiate(&invite(""), &response).is_err());
+}
+
+#[test]
+fn check_interval() -> Result<(), rsip::Error> {
+    assert_eq!(
+        SessionTimer::check_interval(&invite("Session-Expires: 1800\r\n"), 90)?,
+        None
+    );
+    assert_eq!(SessionTimer::check_interval(&invite(""), 90)?, None);
+
+    let response = SessionTimer::check_interval(&invite("Session-Expires: 60\r\n"), 90)?
+        .expect("a 422 response");
+    assert_eq!(response.status_code, StatusCode::SessionIntervalTooSmall);
+    assert_eq!(
+        response.min_se_header().expect("Min-SE header").typed()?,
+        MinSe::new(90)
+    );
+    assert_eq!(response.call_id_header()?, invite("").call_id_header()?);
+    assert!(response.to_string().contains("\r\nMin-SE: 90\r\n"));
+
+    Ok(())
+}